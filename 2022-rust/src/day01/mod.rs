@@ -3,26 +3,22 @@ use std::error::Error;
 use nom::character::complete::u32;
 use nom::combinator::opt;
 
+use log::info;
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     ord::Top,
 };
 
 #[derive(Default)]
 struct Day1 {
-    verbose: bool,
     top: Top<u32, 3>,
     running: u32,
 }
 
 impl Day1 {
-    fn new(verbose: bool) -> Self {
-        Self {
-            verbose,
-            ..Default::default()
-        }
-    }
     fn add(&mut self, calories: u32) {
         self.running += calories;
     }
@@ -43,20 +39,20 @@ impl LineStreamHandler for Day1 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.end_group();
 
         let [top3, top2, top1] = *self.top;
-        println!("[{}] Top Elf:      {}", SILVER_ANSI, top1);
-        println!("[{}] Sum of top 3: {}", GOLD_ANSI, top1 + top2 + top3);
-        if self.verbose {
-            println!("[-] Top 3 Elves:  {} + {} + {}", top1, top2, top3);
-        }
+        info!("Top 3 Elves: {} + {} + {}", top1, top2, top3);
 
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Top Elf", top1)
+            .with_gold("Sum of top 3", top1 + top2 + top3))
     }
 }
 
-pub fn new(verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(1, "Calorie Counting", Day1::new(verbose)))
+pub fn new() -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day1::default()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 1, "Calorie Counting", Parts::Both, |_| new());
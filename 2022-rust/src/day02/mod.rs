@@ -4,7 +4,8 @@ use nom::character::complete::{anychar, char};
 use nom::sequence::separated_pair;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -46,21 +47,15 @@ impl LineStreamHandler for Day2 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Total score: {}",
-            if self.second_char_is_outcome {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.total_score
-        );
-
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.second_char_is_outcome, "Total score", self.total_score))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(2, "Rock Paper Scissors", Day2::new(gold)))
+    Ok(Day::new(Day2::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 2, "Rock Paper Scissors", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -1,5 +1,7 @@
 use std::error::Error;
 
+use aoc_common_rs::error::AocError;
+
 #[derive(Clone, Copy)]
 #[repr(u8)]
 pub enum Gesture {
@@ -28,7 +30,7 @@ impl TryFrom<u8> for Gesture {
             0u8 => Ok(Self::Rock),
             1u8 => Ok(Self::Paper),
             2u8 => Ok(Self::Scissors),
-            _ => Err(Box::from("invalid gesture id")),
+            _ => Err(AocError::invalid_input("invalid gesture id").into()),
         }
     }
 }
@@ -41,7 +43,7 @@ impl TryFrom<char> for Gesture {
             'A' | 'X' => Ok(Self::Rock),
             'B' | 'Y' => Ok(Self::Paper),
             'C' | 'Z' => Ok(Self::Scissors),
-            _ => Err(Box::from("invalid gesture character")),
+            _ => Err(AocError::invalid_input("invalid gesture character").into()),
         }
     }
 }
@@ -74,7 +76,7 @@ impl TryFrom<u8> for Outcome {
             0u8 => Ok(Self::Lose),
             1u8 => Ok(Self::Draw),
             2u8 => Ok(Self::Win),
-            _ => Err(Box::from("invalid outcome id")),
+            _ => Err(AocError::invalid_input("invalid outcome id").into()),
         }
     }
 }
@@ -87,7 +89,7 @@ impl TryFrom<char> for Outcome {
             'X' => Ok(Self::Lose),
             'Y' => Ok(Self::Draw),
             'Z' => Ok(Self::Win),
-            _ => Err(Box::from("invalid outcome character")),
+            _ => Err(AocError::invalid_input("invalid outcome character").into()),
         }
     }
 }
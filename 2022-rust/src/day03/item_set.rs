@@ -1,6 +1,8 @@
 use std::error::Error;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
 
+use aoc_common_rs::error::AocError;
+
 #[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
 #[repr(transparent)]
 pub struct ItemSet(u64);
@@ -11,7 +13,7 @@ impl ItemSet {
     }
     pub fn singleton_priority(self) -> Result<u32, Box<dyn Error>> {
         if 1 != self.0.count_ones() {
-            Err(Box::from("not a singleton"))
+            Err(AocError::invalid_input("not a singleton").into())
         } else {
             Ok(self.0.trailing_zeros() + 1)
         }
@@ -50,12 +52,12 @@ impl TryFrom<char> for ItemSet {
     type Error = Box<dyn Error>;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
-        if value >= 'a' && value <= 'z' {
+        if value.is_ascii_lowercase() {
             Ok(Self(1u64 << (value as u32 - 'a' as u32)))
-        } else if value >= 'A' && value <= 'Z' {
+        } else if value.is_ascii_uppercase() {
             Ok(Self(1u64 << (value as u32 - 'A' as u32 + 26)))
         } else {
-            Err(Box::from("invalid item character"))
+            Err(AocError::invalid_input("invalid item character").into())
         }
     }
 }
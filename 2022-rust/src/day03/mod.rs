@@ -1,7 +1,9 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::LineStreamHandler,
 };
 
@@ -30,7 +32,7 @@ impl Day3 {
 impl LineStreamHandler for Day3 {
     fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         if 0 != (line.len() & 1) {
-            return Err(Box::from("imbalanced halfs"));
+            return Err(AocError::invalid_input("imbalanced halfs").into());
         }
         let (left_half, right_half) = line.split_at(line.len() >> 1);
         let left_set = ItemSet::try_from(left_half)?;
@@ -50,14 +52,15 @@ impl LineStreamHandler for Day3 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!("[{}] Error sum: {}", SILVER_ANSI, self.error_sum);
-        println!("[{}] Badge sum: {}", GOLD_ANSI, self.badge_sum);
-
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Error sum", self.error_sum)
+            .with_gold("Badge sum", self.badge_sum))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(3, "Rucksack Reorganization", Day3::new()))
+    Ok(Day::new(Day3::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 3, "Rucksack Reorganization", Parts::Both, |_| new());
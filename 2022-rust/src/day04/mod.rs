@@ -4,7 +4,8 @@ use nom::character::complete::{char, u32};
 use nom::sequence::separated_pair;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -42,14 +43,15 @@ impl LineStreamHandler for Day4 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!("[{}] Contain: {}", SILVER_ANSI, self.contain);
-        println!("[{}] Overlap: {}", GOLD_ANSI, self.overlap);
-
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Contain", self.contain)
+            .with_gold("Overlap", self.overlap))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(4, "Camp Cleanup", Day4::new()))
+    Ok(Day::new(Day4::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 4, "Camp Cleanup", Parts::Both, |_| new());
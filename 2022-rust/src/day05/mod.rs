@@ -5,8 +5,11 @@ use nom::character::complete::{anychar, u32};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded};
 
+use log::debug;
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandlerOnce},
 };
 
@@ -37,7 +40,7 @@ impl LineStreamHandlerOnce for Day5Stacks {
         Ok(self)
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         unreachable!()
     }
 }
@@ -61,29 +64,18 @@ impl LineStreamHandlerOnce for Day5Moves {
         Ok(self)
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let state = self.0;
-        println!(
-            "[{}] Stack tops: {}",
-            if state.reverse_on_move {
-                SILVER_ANSI
-            } else {
-                GOLD_ANSI
-            },
-            state.tops()?
-        );
-        if state.verbose {
-            state.dump();
-        }
+        debug!("Final stacks:\n{}", state);
 
-        Ok(())
+        Ok(Answers::new().with_part(!state.reverse_on_move, "Stack tops", state.tops()?))
     }
 }
 
-pub fn new(gold: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new_once(
-        5,
-        "Supply Stacks",
-        Day5Stacks(ShipWithCrane::new(!gold, verbose)),
-    ))
+pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new_once(Day5Stacks(ShipWithCrane::new(!gold))))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 5, "Supply Stacks", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
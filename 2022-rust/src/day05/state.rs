@@ -1,18 +1,19 @@
 use std::collections::VecDeque;
 use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use aoc_common_rs::error::AocError;
 
 #[derive(Default)]
 pub struct ShipWithCrane {
     pub reverse_on_move: bool,
-    pub verbose: bool,
     stacks: Vec<VecDeque<char>>,
 }
 
 impl ShipWithCrane {
-    pub fn new(reverse_on_move: bool, verbose: bool) -> Self {
+    pub fn new(reverse_on_move: bool) -> Self {
         Self {
             reverse_on_move,
-            verbose,
             ..Default::default()
         }
     }
@@ -33,13 +34,13 @@ impl ShipWithCrane {
                 if let Some(cr) = self.stacks[from].pop_back() {
                     self.stacks[to].push_back(cr);
                 } else {
-                    return Err(Box::from("moving too many elements"));
+                    return Err(AocError::invalid_input("moving too many elements").into());
                 }
             }
         } else {
             let from_len = self.stacks[from].len();
             if num > from_len {
-                return Err(Box::from("moving too many elements"));
+                return Err(AocError::invalid_input("moving too many elements").into());
             }
             let mut top = self.stacks[from].split_off(from_len - num);
             self.stacks[to].append(&mut top);
@@ -53,14 +54,16 @@ impl ShipWithCrane {
             if let Some(cr) = stack.back() {
                 tops.push(*cr);
             } else {
-                return Err(Box::from("empty stack"));
+                return Err(AocError::invalid_input("empty stack").into());
             }
         }
 
         Ok(tops)
     }
+}
 
-    pub fn dump(&self) {
+impl Display for ShipWithCrane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         let mut max_height = 0;
         for stack in &self.stacks {
             if stack.len() > max_height {
@@ -68,21 +71,22 @@ impl ShipWithCrane {
             }
         }
         for i in (0..max_height).rev() {
-            let mut j: usize = 0;
-            for stack in &self.stacks {
+            if i + 1 < max_height {
+                writeln!(f)?;
+            }
+            for (j, stack) in self.stacks.iter().enumerate() {
                 if j > 0 {
-                    print!(" ");
+                    write!(f, " ")?;
                 }
                 if i >= stack.len() {
-                    print!("   ");
+                    write!(f, "   ")?;
                 } else if i > 0 {
-                    print!("[{}]", stack[i]);
+                    write!(f, "[{}]", stack[i])?;
                 } else {
-                    print!(" {} ", stack[i]);
+                    write!(f, " {} ", stack[i])?;
                 }
-                j = j + 1;
             }
-            println!();
         }
+        Ok(())
     }
 }
@@ -1,7 +1,9 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::{Answers, Value},
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::LineStreamHandler,
 };
 
@@ -10,11 +12,22 @@ const GOLD_MARKER_LENGTH: usize = 14;
 
 struct Day6 {
     marker_length: usize,
+    starts: Vec<Option<usize>>,
 }
 
 impl Day6 {
     fn new(marker_length: usize) -> Self {
-        Self { marker_length }
+        Self {
+            marker_length,
+            starts: Vec::new(),
+        }
+    }
+    fn description(&self) -> &'static str {
+        if self.marker_length == GOLD_MARKER_LENGTH {
+            "message"
+        } else {
+            "packet"
+        }
     }
 }
 
@@ -23,7 +36,7 @@ fn has_all_distinct_chars(s: &str) -> Result<bool, Box<dyn Error>> {
     for ch in s.chars() {
         let ch_ord = ch as u32;
         if ch_ord > 127 {
-            return Err(Box::from("non-ASCII character found"));
+            return Err(AocError::invalid_input("non-ASCII character found").into());
         }
         let ch_mask = 1u128 << ch_ord;
         if 0 != (mask & ch_mask) {
@@ -39,40 +52,45 @@ fn has_all_distinct_chars(s: &str) -> Result<bool, Box<dyn Error>> {
 impl LineStreamHandler for Day6 {
     fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         let line_len = line.len();
-        let prefix = if self.marker_length == GOLD_MARKER_LENGTH {
-            GOLD_ANSI
-        } else {
-            SILVER_ANSI
-        };
-        let description = if self.marker_length == GOLD_MARKER_LENGTH {
-            "message"
-        } else {
-            "packet"
-        };
         for i in self.marker_length..=line_len {
             if has_all_distinct_chars(&line[(i - self.marker_length)..i])? {
-                println!("[{}] Start of {}: {}", prefix, description, i);
+                self.starts.push(Some(i));
                 return Ok(());
             }
         }
-        println!("[{}] No start of {}", prefix, description);
+        self.starts.push(None);
 
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        Ok(())
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let label = format!("Start of {}", self.description());
+        let value = |start: Option<usize>| start.map_or_else(|| "none".into(), Value::from);
+        let last = self
+            .starts
+            .pop()
+            .ok_or_else(|| AocError::invalid_input("empty input"))?;
+        let mut answers = Answers::new().with_part(
+            self.marker_length == GOLD_MARKER_LENGTH,
+            &label,
+            value(last),
+        );
+        for (i, start) in self.starts.iter().enumerate() {
+            answers.push_extra(format!("{} (line {})", label, i + 1), value(*start));
+        }
+
+        Ok(answers)
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        6,
-        "Tuning Trouble",
-        Day6::new(if gold {
-            GOLD_MARKER_LENGTH
-        } else {
-            SILVER_MARKER_LENGTH
-        }),
-    ))
+    Ok(Day::new(Day6::new(if gold {
+        GOLD_MARKER_LENGTH
+    } else {
+        SILVER_MARKER_LENGTH
+    })))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 6, "Tuning Trouble", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -1,8 +1,11 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::error::Error;
+use std::fmt::Write;
 use std::rc::{Rc, Weak};
 
+use aoc_common_rs::error::AocError;
+
 pub struct Directory {
     pub parent: Weak<Directory>,
     current: Weak<Directory>,
@@ -36,9 +39,10 @@ impl Directory {
             return if let Entry::Directory(directory) = entry {
                 Ok(directory.to_owned())
             } else {
-                Err(Box::from(
-                    "entry already exists with type other than directory",
-                ))
+                Err(
+                    AocError::invalid_input("entry already exists with type other than directory")
+                        .into(),
+                )
             };
         }
 
@@ -56,10 +60,13 @@ impl Directory {
                 if *ent_size == size {
                     Ok(())
                 } else {
-                    Err(Box::from("file entry already exists with other size"))
+                    Err(AocError::invalid_input("file entry already exists with other size").into())
                 }
             } else {
-                Err(Box::from("entry already exists with type other than file"))
+                Err(
+                    AocError::invalid_input("entry already exists with type other than file")
+                        .into(),
+                )
             };
         }
 
@@ -70,18 +77,21 @@ impl Directory {
 
         Ok(())
     }
-    pub fn dump(&self, level: usize, name: &str) {
-        println!("{:indent$}- {} (dir)", "", name, indent = level);
+    pub fn dump(&self, out: &mut String, level: usize, name: &str) {
+        let _ = writeln!(out, "{:indent$}- {} (dir)", "", name, indent = level);
         for (c_name, entry) in self.children.borrow().iter() {
             match entry {
-                Entry::File { size } => println!(
-                    "{:indent$}  - {} (file, size={})",
-                    "",
-                    c_name,
-                    size,
-                    indent = level
-                ),
-                Entry::Directory(dir) => dir.dump(level + 2, c_name.as_str()),
+                Entry::File { size } => {
+                    let _ = writeln!(
+                        out,
+                        "{:indent$}  - {} (file, size={})",
+                        "",
+                        c_name,
+                        size,
+                        indent = level
+                    );
+                }
+                Entry::Directory(dir) => dir.dump(out, level + 2, c_name.as_str()),
             }
         }
     }
@@ -6,8 +6,11 @@ use nom::character::complete::{char, u32};
 use nom::combinator::{map, rest};
 use nom::sequence::{preceded, separated_pair};
 
+use log::{debug, log_enabled, Level};
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -18,19 +21,9 @@ use state::Session;
 
 #[derive(Default)]
 struct Day7 {
-    verbose: bool,
     state: Session,
 }
 
-impl Day7 {
-    fn new(verbose: bool) -> Self {
-        Self {
-            verbose,
-            ..Default::default()
-        }
-    }
-}
-
 enum SessionLine<'a> {
     MoveToRootCommand,
     MoveToParentCommand,
@@ -42,16 +35,17 @@ enum SessionLine<'a> {
 
 impl<'a> SessionLine<'a> {
     fn update_state(self, state: &mut Session) -> Result<(), Box<dyn Error>> {
-        Ok(match self {
+        match self {
             Self::MoveToRootCommand => state.move_to_root(),
             Self::MoveToParentCommand => state.move_to_parent()?,
-            Self::MoveToChildCommand { name } => state.move_to_child(&name)?,
+            Self::MoveToChildCommand { name } => state.move_to_child(name)?,
             Self::ListCommand => (),
             Self::DirectoryEntry { name } => {
-                state.assert_directory(&name)?;
+                state.assert_directory(name)?;
             }
-            Self::FileEntry { size, name } => state.assert_file(&name, size)?,
-        })
+            Self::FileEntry { size, name } => state.assert_file(name, size)?,
+        }
+        Ok(())
     }
 }
 
@@ -89,25 +83,25 @@ impl LineStreamHandler for Day7 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Small dirs total size: {}",
-            SILVER_ANSI,
-            self.state.small_dirs_total(100_000)
-        );
-        println!(
-            "[{}] Dir to delete size:    {}",
-            GOLD_ANSI,
-            self.state.to_delete_size(70_000_000 - 30_000_000)
-        );
-        if self.verbose {
-            self.state.dump_fs();
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        if log_enabled!(Level::Debug) {
+            debug!("File system:\n{}", self.state.dump_fs());
         }
 
-        Ok(())
+        Ok(Answers::new()
+            .with_silver(
+                "Small dirs total size",
+                self.state.small_dirs_total(100_000),
+            )
+            .with_gold(
+                "Dir to delete size",
+                self.state.to_delete_size(70_000_000 - 30_000_000),
+            ))
     }
 }
 
-pub fn new(verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(7, "No Space Left On Device", Day7::new(verbose)))
+pub fn new() -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day7::default()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 7, "No Space Left On Device", Parts::Both, |_| new());
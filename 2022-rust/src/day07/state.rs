@@ -3,6 +3,8 @@ use std::rc::Rc;
 
 use super::fs::{Directory, Entry};
 
+use aoc_common_rs::error::AocError;
+
 pub struct Session {
     root: Rc<Directory>,
     cwd: Rc<Directory>,
@@ -18,7 +20,7 @@ impl Session {
 
             Ok(())
         } else {
-            Err(Box::from("parent has been freed"))
+            Err(AocError::invalid_input("parent has been freed").into())
         }
     }
     pub fn move_to_child(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
@@ -45,8 +47,11 @@ impl Session {
         smallest_larger_than(&self.root, size - max_used)
     }
 
-    pub fn dump_fs(&self) {
-        self.root.dump(0, "/");
+    pub fn dump_fs(&self) -> String {
+        let mut out = String::new();
+        self.root.dump(&mut out, 0, "/");
+        out.truncate(out.trim_end().len());
+        out
     }
 }
 
@@ -1,5 +1,7 @@
 use std::error::Error;
+use std::fmt::Write;
 
+use aoc_common_rs::error::AocError;
 use aoc_common_rs::math::scale_u8;
 use aoc_common_rs::point::Point2;
 use aoc_common_rs::terrain::Terrain;
@@ -39,17 +41,17 @@ impl TryFrom<char> for Tree {
     type Error = Box<dyn Error>;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
-        if value >= '0' && value <= '9' {
-            Ok(Self::new(value as u8 - '0' as u8 + 1))
+        if value.is_ascii_digit() {
+            Ok(Self::new(value as u8 - b'0' + 1))
         } else {
-            Err(Box::from("invalid tree character"))
+            Err(AocError::invalid_input("invalid tree character").into())
         }
     }
 }
 
 impl From<&Tree> for char {
     fn from(value: &Tree) -> Self {
-        (value.height - 1 + '0' as u8) as Self
+        (value.height - 1 + b'0') as Self
     }
 }
 
@@ -65,16 +67,16 @@ impl Map {
     pub fn new_row(&self) -> Vec<Tree> {
         self.terrain.new_row()
     }
-    pub fn push_row(&mut self, mut row: Vec<Tree>) {
+    pub fn push_row(&mut self, mut row: Vec<Tree>) -> Result<(), Box<dyn Error>> {
         calculate_ew_visibilities(row.iter_mut(), &mut self.visible_count);
         calculate_ew_visibilities(row.iter_mut().rev(), &mut self.visible_count);
-        self.terrain.push_row(row);
+        self.terrain.push_row(row)
     }
     pub fn calculate_ns_visibilities(&mut self) {
         let width = self.terrain.width();
-        calculate_ns_visibilities(self.terrain.iter_mut(), width, &mut self.visible_count);
+        calculate_ns_visibilities(self.terrain.rows_mut(), width, &mut self.visible_count);
         calculate_ns_visibilities(
-            self.terrain.iter_mut().rev(),
+            self.terrain.rows_mut().rev(),
             width,
             &mut self.visible_count,
         );
@@ -113,22 +115,26 @@ impl Map {
 
         view_north * view_south * view_west * view_east
     }
-    pub fn dump(&self) {
-        for row in self.terrain.iter() {
+    pub fn dump(&self) -> String {
+        let mut out = String::new();
+        for row in self.terrain.rows() {
             let mut visible: bool = true;
-            print!("\x1B[38;5;10m");
+            out.push_str("\x1B[38;5;10m");
             for tree in row {
                 if visible != tree.visible {
                     visible = tree.visible;
-                    print!("\x1B[38;5;{}m", if visible { "10" } else { "3" });
+                    let _ = write!(out, "\x1B[38;5;{}m", if visible { "10" } else { "3" });
                 }
-                print!("{}", tree.height - 1);
+                let _ = write!(out, "{}", tree.height - 1);
             }
-            println!("\x1B[m");
+            let _ = writeln!(out, "\x1B[m");
         }
+        out.truncate(out.trim_end().len());
+        out
     }
-    pub fn dump_extended(&self) {
-        for row in self.terrain.iter() {
+    pub fn dump_extended(&self) -> String {
+        let mut out = String::new();
+        for row in self.terrain.rows() {
             for tree in row {
                 let visibility: u8 = if tree.visible { 255 } else { 128 };
                 let max_score = if tree.visible {
@@ -139,7 +145,8 @@ impl Map {
                 let scenic_quality: u8 = (tree.scenic_score * 255 / max_score) as u8;
                 let high_byte = scale_u8(128 + scale_u8(scenic_quality, 127), visibility);
                 let low_byte = scale_u8(128 - scale_u8(scenic_quality, 128), visibility);
-                print!(
+                let _ = write!(
+                    out,
                     "\x1B[38;2;{};{};{}m{}",
                     if tree.visible { low_byte } else { high_byte },
                     high_byte,
@@ -147,8 +154,10 @@ impl Map {
                     char::from(tree),
                 );
             }
-            println!("\x1B[m");
+            let _ = writeln!(out, "\x1B[m");
         }
+        out.truncate(out.trim_end().len());
+        out
     }
 }
 
@@ -180,7 +189,7 @@ fn calculate_ew_visibilities<'a, 'b>(
 }
 
 fn calculate_ns_visibilities<'a, 'b>(
-    map: impl Iterator<Item = &'a mut Vec<Tree>>,
+    map: impl Iterator<Item = &'a mut [Tree]>,
     width: usize,
     visible_count: &'b mut usize,
 ) {
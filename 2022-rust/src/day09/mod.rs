@@ -3,8 +3,11 @@ use std::error::Error;
 use nom::character::complete::{anychar, char, u16};
 use nom::sequence::separated_pair;
 
+use log::{debug, info, log_enabled, trace, Level};
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     point::{Direction2, Point2},
 };
@@ -15,7 +18,6 @@ use rope::{pull_towards, TracedPoint};
 
 #[derive(Default)]
 struct Day9 {
-    verbosity: u8,
     head: Point2<i16>,
     intermediate: Vec<Point2<i16>>,
     tail: TracedPoint<i16>,
@@ -24,18 +26,15 @@ struct Day9 {
 }
 
 impl Day9 {
-    fn new(intermediate_knots: usize, verbosity: u8) -> Self {
+    fn new(intermediate_knots: usize) -> Self {
         Self {
-            verbosity,
             intermediate: vec![Default::default(); intermediate_knots],
             ..Default::default()
         }
     }
     fn move_head(&mut self, direction: Direction2, distance: u16) {
-        if self.verbosity > 2 {
-            println!("[-] Moving head by {} towards {:?}", distance, direction);
-        }
-        for i in 0..distance {
+        debug!("Moving head by {} towards {:?}", distance, direction);
+        for _ in 0..distance {
             let prev_head = self.head;
             self.head = self.head.next_towards(direction);
             self.min = self.min.componentwise_min(self.head);
@@ -51,58 +50,54 @@ impl Day9 {
                 }
                 self.tail.pull_towards(intermediate, prev_intermediate);
             }
-            if self.verbosity > 3 {
-                if i > 0 {
-                    println!()
-                }
-                self.dump_current()
+            if log_enabled!(Level::Trace) {
+                trace!("Rope:\n{}", self.dump_current());
             }
         }
-        if self.verbosity == 3 {
-            self.dump_current()
-        }
     }
-    fn dump_current(&self) {
+    fn dump_current(&self) -> String {
+        let mut out = String::new();
         for y in self.min.1..=self.max.1 {
+            if y > self.min.1 {
+                out.push('\n');
+            }
             for x in self.min.0..=self.max.0 {
                 let pt = Point2(x, y);
-                print!(
-                    "{}",
-                    if pt == self.head {
-                        'H'
-                    } else if let Some(index) = self.intermediate.iter().position(|p| *p == pt) {
-                        (index as u8 + '1' as u8) as char
-                    } else if pt == self.tail.current {
-                        'T'
-                    } else if pt == Default::default() {
-                        's'
-                    } else if self.tail.trace.contains(&pt) {
-                        '#'
-                    } else {
-                        '.'
-                    }
-                );
+                out.push(if pt == self.head {
+                    'H'
+                } else if let Some(index) = self.intermediate.iter().position(|p| *p == pt) {
+                    (index as u8 + b'1') as char
+                } else if pt == self.tail.current {
+                    'T'
+                } else if pt == Default::default() {
+                    's'
+                } else if self.tail.trace.contains(&pt) {
+                    '#'
+                } else {
+                    '.'
+                });
             }
-            println!()
         }
+        out
     }
-    fn dump_trace(&self) {
+    fn dump_trace(&self) -> String {
+        let mut out = String::new();
         for y in self.min.1..=self.max.1 {
+            if y > self.min.1 {
+                out.push('\n');
+            }
             for x in self.min.0..=self.max.0 {
                 let pt = Point2(x, y);
-                print!(
-                    "{}",
-                    if pt == Default::default() {
-                        's'
-                    } else if self.tail.trace.contains(&pt) {
-                        '#'
-                    } else {
-                        '.'
-                    }
-                )
+                out.push(if pt == Default::default() {
+                    's'
+                } else if self.tail.trace.contains(&pt) {
+                    '#'
+                } else {
+                    '.'
+                });
             }
-            println!()
         }
+        out
     }
 }
 
@@ -115,30 +110,25 @@ impl LineStreamHandler for Day9 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Points in tail trace: {}",
-            if self.intermediate.is_empty() {
-                SILVER_ANSI
-            } else {
-                GOLD_ANSI
-            },
-            self.tail.trace.len()
-        );
-        if self.verbosity > 1 {
-            self.dump_current()
-        } else if self.verbosity > 0 {
-            self.dump_trace()
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        if log_enabled!(Level::Debug) {
+            debug!("Rope:\n{}", self.dump_current());
+        } else if log_enabled!(Level::Info) {
+            info!("Tail trace:\n{}", self.dump_trace());
         }
 
-        Ok(())
+        Ok(Answers::new().with_part(
+            !self.intermediate.is_empty(),
+            "Points in tail trace",
+            self.tail.trace.len(),
+        ))
     }
 }
 
-pub fn new(gold: bool, verbosity: u8) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        9,
-        "Rope Bridge",
-        Day9::new(if gold { 8 } else { 0 }, verbosity),
-    ))
+pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day9::new(if gold { 8 } else { 0 })))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 9, "Rope Bridge", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -6,14 +6,17 @@ use nom::character::complete::i32;
 use nom::combinator::map;
 use nom::sequence::preceded;
 
+use itertools::Itertools;
+use log::debug;
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
 #[derive(Default)]
 struct Day10 {
-    verbose: bool,
     time: u32,
     register: i32,
     sum: i32,
@@ -21,9 +24,8 @@ struct Day10 {
 }
 
 impl Day10 {
-    fn new(verbose: bool) -> Self {
+    fn new() -> Self {
         Self {
-            verbose,
             register: 1,
             ..Default::default()
         }
@@ -39,9 +41,7 @@ impl Day10 {
             self.time += 1;
             if (self.time % 40) == 20 {
                 self.sum += self.time as i32 * self.register;
-                if self.verbose {
-                    println!("[-] Cycle {}, X = {}", self.time, self.register);
-                }
+                debug!("Cycle {}, X = {}", self.time, self.register);
             }
         }
     }
@@ -70,7 +70,7 @@ impl LineStreamHandler for Day10 {
             line,
             alt((
                 map(tag("noop"), |_| Instruction::NoOp),
-                map(preceded(tag("addx "), i32), |arg| Instruction::AddX(arg)),
+                map(preceded(tag("addx "), i32), Instruction::AddX),
             )),
         )?;
         self.execute(instruction);
@@ -78,20 +78,25 @@ impl LineStreamHandler for Day10 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!("[{}] Sum of strengths: {}", SILVER_ANSI, self.sum);
-        println!("[{}] Screen contents:", GOLD_ANSI);
-        for row in self.screen {
-            for bit in 0..40 {
-                print!("{}", if 0 != row & (1 << bit) { '#' } else { '.' });
-            }
-            println!();
-        }
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let screen = self
+            .screen
+            .iter()
+            .map(|row| {
+                (0..40)
+                    .map(|bit| if 0 != row & (1 << bit) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .join("\n");
 
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Sum of strengths", self.sum)
+            .with_gold("Screen contents", screen))
     }
 }
 
-pub fn new(verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(10, "Cathode-Ray Tube", Day10::new(verbose)))
+pub fn new() -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day10::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 10, "Cathode-Ray Tube", Parts::Both, |_| new());
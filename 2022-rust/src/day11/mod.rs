@@ -7,8 +7,11 @@ use nom::combinator::{map, opt};
 use nom::multi::separated_list0;
 use nom::sequence::{delimited, preceded};
 
+use log::{debug, info, log_enabled, trace, Level};
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -18,27 +21,25 @@ use state::{Monkey, Operation, State};
 #[derive(Default)]
 struct Day11 {
     gold: bool,
-    verbosity: u8,
     current: usize,
     state: State,
 }
 
 impl Day11 {
-    fn new(gold: bool, verbosity: u8) -> Self {
+    fn new(gold: bool) -> Self {
         Self {
             gold,
-            verbosity,
             ..Default::default()
         }
     }
     fn play_rounds(&mut self, rounds: u64) {
-        for _ in 0..rounds {
+        for round in 1..=rounds {
             self.state.play_round();
-            if self.verbosity > 2 {
-                self.state.dump_items();
+            if log_enabled!(Level::Trace) {
+                trace!("After round {}:\n{}", round, self.state.dump_items());
             }
-            if self.verbosity > 1 {
-                self.state.dump_inspections();
+            if log_enabled!(Level::Debug) {
+                debug!("After round {}:\n{}", round, self.state.dump_inspections());
             }
         }
     }
@@ -62,7 +63,7 @@ enum Line {
 
 impl Line {
     fn update_state(self, state: &mut Day11) -> Result<(), Box<dyn Error>> {
-        Ok(match self {
+        match self {
             Self::SelectMonkey(n) => {
                 state.select_monkey(n);
             }
@@ -83,7 +84,8 @@ impl Line {
                 state.state.ensure_monkey_exists(monkey);
                 state.current_monkey_mut().next_if_false = monkey;
             }
-        })
+        }
+        Ok(())
     }
 }
 
@@ -97,23 +99,23 @@ impl LineStreamHandler for Day11 {
                 }),
                 map(
                     preceded(tag("  Starting items: "), separated_list0(tag(", "), u64)),
-                    |items| Line::SetItems(items),
+                    Line::SetItems,
                 ),
                 map(
                     preceded(
                         tag("  Operation: new = old "),
                         alt((
-                            map(preceded(tag("+ "), u64), |n| Operation::Add(n)),
+                            map(preceded(tag("+ "), u64), Operation::Add),
                             preceded(
                                 tag("* "),
                                 alt((
-                                    map(u64, |n| Operation::Mul(n)),
+                                    map(u64, Operation::Mul),
                                     map(tag("old"), |_| Operation::Square),
                                 )),
                             ),
                         )),
                     ),
-                    |operation| Line::SetOperation(operation),
+                    Line::SetOperation,
                 ),
                 map(preceded(tag("  Test: divisible by "), u64), |divisor| {
                     Line::SetTestDivisibleBy(divisor)
@@ -136,27 +138,30 @@ impl LineStreamHandler for Day11 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.state.determine_post_operation(self.gold);
         self.play_rounds(if self.gold { 10000 } else { 20 });
 
-        if self.verbosity == 1 {
-            self.state.dump_inspections();
+        if log_enabled!(Level::Info) {
+            info!("Inspections:\n{}", self.state.dump_inspections());
         }
-        println!(
-            "[{}] Monkey business level: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            self.state.monkey_business_level()
-        );
 
-        Ok(())
+        Ok(Answers::new().with_part(
+            self.gold,
+            "Monkey business level",
+            self.state.monkey_business_level(),
+        ))
     }
 }
 
-pub fn new(gold: bool, verbosity: u8) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        11,
-        "Monkey in the Middle",
-        Day11::new(gold, verbosity),
-    ))
+pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day11::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2022,
+    11,
+    "Monkey in the Middle",
+    Parts::Selectable,
+    |args| new(args.flag("gold")),
+);
@@ -1,3 +1,5 @@
+use itertools::Itertools;
+
 use aoc_common_rs::math::lcm;
 use aoc_common_rs::ord::Top;
 
@@ -38,7 +40,7 @@ pub struct Monkey {
 impl Monkey {
     fn inspect(&mut self, item: u64, post_operation: Operation) -> (u64, usize) {
         let new_item = post_operation.apply(self.operation.apply(item));
-        let next = if (new_item % self.test_divisible_by) == 0 {
+        let next = if new_item.is_multiple_of(self.test_divisible_by) {
             self.next_if_true
         } else {
             self.next_if_false
@@ -99,17 +101,20 @@ impl State {
 
         top1 * top2
     }
-    pub fn dump_items(&self) {
-        for (monkey, i) in self.monkeys.iter().zip(0usize..) {
-            println!("[-] Monkey {}: {:?}", i, monkey.items);
-        }
+    pub fn dump_items(&self) -> String {
+        self.monkeys
+            .iter()
+            .zip(0usize..)
+            .map(|(monkey, i)| format!("Monkey {}: {:?}", i, monkey.items))
+            .join("\n")
     }
-    pub fn dump_inspections(&self) {
-        for (monkey, i) in self.monkeys.iter().zip(0usize..) {
-            println!(
-                "[-] Monkey {} inspected items {} times.",
-                i, monkey.inspections
-            );
-        }
+    pub fn dump_inspections(&self) -> String {
+        self.monkeys
+            .iter()
+            .zip(0usize..)
+            .map(|(monkey, i)| {
+                format!("Monkey {} inspected items {} times.", i, monkey.inspections)
+            })
+            .join("\n")
     }
 }
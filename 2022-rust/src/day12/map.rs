@@ -1,7 +1,10 @@
 use std::error::Error;
 
+use itertools::Itertools;
+
+use aoc_common_rs::error::AocError;
 use aoc_common_rs::point::{Direction2, Point2};
-use aoc_common_rs::terrain::Terrain;
+use aoc_common_rs::terrain::{Markers, Terrain};
 
 #[derive(Debug, Default)]
 #[repr(u8)]
@@ -16,8 +19,6 @@ pub enum PathEnd {
 pub struct TerrainCell {
     height: u8,
     path_end: PathEnd,
-    distance: Option<usize>,
-    towards_start: Option<Direction2>,
     towards_end: Option<Direction2>,
 }
 
@@ -39,10 +40,10 @@ impl TryFrom<char> for TerrainCell {
             Ok(Self::new(1, PathEnd::Start))
         } else if value == 'E' {
             Ok(Self::new(26, PathEnd::End))
-        } else if value >= 'a' && value <= 'z' {
-            Ok(Self::new(value as u8 - 'a' as u8 + 1, PathEnd::None))
+        } else if value.is_ascii_lowercase() {
+            Ok(Self::new(value as u8 - b'a' + 1, PathEnd::None))
         } else {
-            Err(Box::from("invalid terrain character"))
+            Err(AocError::invalid_input("invalid terrain character").into())
         }
     }
 }
@@ -60,97 +61,71 @@ impl From<&TerrainCell> for char {
             match value.path_end {
                 PathEnd::Start => 'S',
                 PathEnd::End => 'E',
-                PathEnd::None => (value.height - 1 + 'a' as u8) as char,
+                PathEnd::None => (value.height - 1 + b'a') as char,
             }
         }
     }
 }
 
-#[derive(Default)]
+fn climbable(from: &TerrainCell, to: &TerrainCell) -> bool {
+    to.height <= from.height + 1
+}
+
 pub struct Map {
     terrain: Terrain<TerrainCell>,
     start: Point2<usize>,
     end: Point2<usize>,
+    distances: Terrain<Option<usize>>,
     path: Vec<Point2<usize>>,
 }
 
 impl Map {
-    pub fn new_row(&self) -> Vec<TerrainCell> {
-        self.terrain.new_row()
-    }
-    pub fn push_row(&mut self, row: Vec<TerrainCell>) {
-        self.terrain.push_row(row);
-    }
-    pub fn update(&mut self, cell: &TerrainCell, j: usize) {
-        match cell.path_end {
-            PathEnd::None => {}
-            PathEnd::Start => {
-                self.start = Point2(j, self.terrain.height());
-            }
-            PathEnd::End => {
-                self.end = Point2(j, self.terrain.height());
-            }
-        }
+    pub fn new(terrain: Terrain<TerrainCell>, markers: &Markers) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            terrain,
+            start: markers.one('S')?,
+            end: markers.one('E')?,
+            distances: Terrain::new(),
+            path: Vec::new(),
+        })
     }
     pub fn climbing_distance(&self) -> Option<usize> {
-        self.terrain[self.end].distance
+        self.distances[self.start]
     }
+    /// Calculates the distance from every point to the end, starting from
+    /// the closest lowest point instead of the start if `from_any`.
     pub fn calculate_distances(&mut self, from_any: bool) {
-        let mut queue: Vec<Point2<usize>> = Vec::new();
+        self.distances = self.terrain.distances_to([self.end], climbable);
         if from_any {
-            for pt in self.terrain.points() {
-                if self.terrain[pt].height == 1 {
-                    queue.push(pt);
-                }
-            }
-        } else {
-            queue.push(self.start);
-        }
-        for pt in queue.iter() {
-            self.terrain[*pt].distance = Some(0);
-        }
-        for distance in 1usize.. {
-            let pts = queue.split_off(0);
-            if pts.is_empty() {
-                break;
-            }
-            for pt in pts {
-                let height = self.terrain[pt].height;
-                for (direction, neighbor) in self.terrain.neighbors(pt).collect::<Vec<_>>() {
-                    if self.terrain[neighbor].height <= height + 1
-                        && self.terrain[neighbor].distance.is_none()
-                    {
-                        self.terrain[neighbor].distance = Some(distance);
-                        self.terrain[neighbor].towards_start = Some(-direction);
-                        queue.push(neighbor);
-                    }
-                }
+            if let Some(start) = self
+                .terrain
+                .points()
+                .filter(|pt| self.terrain[*pt].height == 1 && self.distances[*pt].is_some())
+                .min_by_key(|pt| self.distances[*pt])
+            {
+                self.start = start;
             }
         }
     }
     pub fn calculate_path(&mut self) {
-        let mut rev_path: Vec<Point2<usize>> = Vec::new();
-        let mut pt = self.end;
-        loop {
-            rev_path.push(pt);
-            if let Some(direction) = self.terrain[pt].towards_start {
-                pt = pt.next_towards(direction);
-                self.terrain[pt].towards_end = Some(-direction);
-            } else {
+        let mut pt = self.start;
+        self.path.push(pt);
+        while let Some(distance) = self.distances[pt].filter(|distance| *distance > 0) {
+            let Some((direction, next)) = self.terrain.neighbors(pt).find(|(_, next)| {
+                self.distances[*next] == Some(distance - 1)
+                    && climbable(&self.terrain[pt], &self.terrain[*next])
+            }) else {
                 break;
-            }
-        }
-        self.path.reserve(rev_path.len());
-        for pt in rev_path.iter().rev() {
-            self.path.push(*pt);
+            };
+            self.terrain[pt].towards_end = Some(direction);
+            pt = next;
+            self.path.push(pt);
         }
     }
-    pub fn dump(&self) {
-        for row in self.terrain.iter() {
-            for cell in row {
-                print!("{}", char::from(cell));
-            }
-            println!();
-        }
+    pub fn dump(&self) -> String {
+        self.terrain
+            .rows()
+            .map(|row| row.iter().map(char::from).collect::<String>())
+            .join("\n")
     }
 }
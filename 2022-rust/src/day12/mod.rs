@@ -1,79 +1,58 @@
 use std::error::Error;
 
+use log::{debug, log_enabled, Level};
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::LineStreamHandler,
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
+    terrain::Terrain,
 };
 
 mod map;
 
-use map::{Map, TerrainCell};
+use map::Map;
 
-#[derive(Default)]
 struct Day12 {
     from_any: bool,
-    verbose: bool,
     map: Map,
 }
 
 impl Day12 {
-    fn new(from_any: bool, verbose: bool) -> Self {
-        Self {
-            from_any,
-            verbose,
-            ..Default::default()
-        }
-    }
-}
-
-impl LineStreamHandler for Day12 {
-    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
-        let mut row = self.map.new_row();
-        for (ch, j) in line.chars().zip(0usize..) {
-            let cell = TerrainCell::try_from(ch)?;
-            self.map.update(&cell, j);
-            row.push(cell);
-        }
-        self.map.push_row(row);
-
-        Ok(())
+    fn new(from_any: bool, map: Map) -> Self {
+        Self { from_any, map }
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self) -> Result<Answers, Box<dyn Error>> {
         self.map.calculate_distances(self.from_any);
-        if let Some(distance) = self.map.climbing_distance() {
-            println!(
-                "[{}] Climbing distance: {}",
-                if self.from_any {
-                    GOLD_ANSI
-                } else {
-                    SILVER_ANSI
-                },
-                distance
-            );
-        } else {
-            println!(
-                "[{}] Cannot calculate climbing distance",
-                if self.from_any {
-                    GOLD_ANSI
-                } else {
-                    SILVER_ANSI
-                }
-            );
-        }
-        if self.verbose {
+        if log_enabled!(Level::Debug) {
             self.map.calculate_path();
-            self.map.dump();
+            debug!("Climbing path:\n{}", self.map.dump());
         }
 
-        Ok(())
+        let distance = self
+            .map
+            .climbing_distance()
+            .ok_or_else(|| AocError::unsolvable("cannot calculate climbing distance"))?;
+
+        Ok(Answers::new().with_part(self.from_any, "Climbing distance", distance))
     }
 }
 
-pub fn new(gold: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
+pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
     Ok(Day::new(
-        12,
-        "Hill Climbing Algorithm",
-        Day12::new(gold, verbose),
+        Terrain::builder()
+            .with_markers("SE")
+            .into_handler(move |terrain, markers| {
+                Day12::new(gold, Map::new(terrain, &markers)?).finish()
+            }),
     ))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2022,
+    12,
+    "Hill Climbing Algorithm",
+    Parts::Selectable,
+    |args| new(args.flag("gold")),
+);
@@ -3,7 +3,8 @@ use std::error::Error;
 use nom::combinator::opt;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     unwrap_either,
 };
@@ -29,37 +30,30 @@ impl LineStreamHandler for Day13 {
         if let Some(packet) = parse_full_string(line, opt(packet))? {
             self.packets.push(packet);
             let packets_so_far = self.packets.len();
-            if 0 == (packets_so_far % 2) {
-                if self.packets[packets_so_far - 2] < self.packets[packets_so_far - 1] {
-                    self.well_ordered_index_sum += packets_so_far / 2;
-                }
+            if packets_so_far.is_multiple_of(2)
+                && self.packets[packets_so_far - 2] < self.packets[packets_so_far - 1]
+            {
+                self.well_ordered_index_sum += packets_so_far / 2;
             }
         }
 
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.packets.sort_unstable();
 
-        println!(
-            "[{}] Well-ordered index sum: {}",
-            SILVER_ANSI, self.well_ordered_index_sum
-        );
-
         let pos2 = unwrap_either(self.packets.binary_search(&divider(2)));
         let pos6 = unwrap_either(self.packets.binary_search(&divider(6)));
 
-        println!(
-            "[{}] Decoder key:            {}",
-            GOLD_ANSI,
-            (pos2 + 1) * (pos6 + 2)
-        );
-
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Well-ordered index sum", self.well_ordered_index_sum)
+            .with_gold("Decoder key", (pos2 + 1) * (pos6 + 2)))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(13, "Distress Signal", Day13::new()))
+    Ok(Day::new(Day13::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 13, "Distress Signal", Parts::Both, |_| new());
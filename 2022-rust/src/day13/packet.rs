@@ -78,7 +78,7 @@ pub fn packet<'a, E: ParseError<&'a str>>(s: &'a str) -> IResult<&'a str, Packet
             delimited(char('['), separated_list0(char(','), packet), char(']')),
             |list| Packet::List(Rc::new(list)),
         ),
-        map(u32, |num| Packet::Integer(num)),
+        map(u32, Packet::Integer),
     ))
     .parse(s)
 }
@@ -7,8 +7,12 @@ use nom::combinator::map;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 
+use log::{debug, log_enabled, Level};
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
     point::{Direction2, Point2},
 };
@@ -18,7 +22,6 @@ const SAND_START: Point2<u16> = Point2(500, 0);
 #[derive(Default)]
 struct Day14 {
     with_floor: bool,
-    verbose: bool,
     min: Point2<u16>,
     max: Point2<u16>,
     solid: HashSet<Point2<u16>>,
@@ -26,10 +29,9 @@ struct Day14 {
 }
 
 impl Day14 {
-    fn new(with_floor: bool, verbose: bool) -> Self {
+    fn new(with_floor: bool) -> Self {
         Self {
             with_floor,
-            verbose,
             min: SAND_START,
             max: SAND_START,
             ..Default::default()
@@ -49,7 +51,7 @@ impl Day14 {
 
             Ok(())
         } else {
-            Err(Box::from("non-axis-aligned line"))
+            Err(AocError::invalid_input("non-axis-aligned line").into())
         }
     }
     fn add_floor(&mut self) -> Result<(), Box<dyn Error>> {
@@ -101,25 +103,26 @@ impl Day14 {
 
         None
     }
-    fn dump(&self) {
+    fn dump(&self) -> String {
+        let mut out = String::new();
         for y in self.min.1..=self.max.1 {
+            if y > self.min.1 {
+                out.push('\n');
+            }
             for x in self.min.0..=self.max.0 {
                 let pt = Point2(x, y);
-                print!(
-                    "{}",
-                    if pt == SAND_START {
-                        '+'
-                    } else if self.sand.contains(&pt) {
-                        'o'
-                    } else if self.solid.contains(&pt) {
-                        '#'
-                    } else {
-                        '.'
-                    }
-                )
+                out.push(if pt == SAND_START {
+                    '+'
+                } else if self.sand.contains(&pt) {
+                    'o'
+                } else if self.solid.contains(&pt) {
+                    '#'
+                } else {
+                    '.'
+                });
             }
-            println!()
         }
+        out
     }
 }
 
@@ -139,34 +142,24 @@ impl LineStreamHandler for Day14 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if self.with_floor {
             self.add_floor()?;
         }
         self.add_all_sand();
 
-        println!(
-            "[{}] Units of sand: {}",
-            if self.with_floor {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.sand.len()
-        );
-
-        if self.verbose {
-            self.dump();
+        if log_enabled!(Level::Debug) {
+            debug!("Cave:\n{}", self.dump());
         }
 
-        Ok(())
+        Ok(Answers::new().with_part(self.with_floor, "Units of sand", self.sand.len()))
     }
 }
 
-pub fn new(gold: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        14,
-        "Regolith Reservoir",
-        Day14::new(gold, verbose),
-    ))
+pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day14::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 14, "Regolith Reservoir", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
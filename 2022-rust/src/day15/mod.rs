@@ -7,8 +7,12 @@ use nom::character::complete::i32;
 use nom::combinator::map;
 use nom::sequence::{preceded, separated_pair};
 
+use log::{debug, trace};
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
     math::abs_diff,
     multi_range::MultiRangeInclusive,
@@ -39,18 +43,15 @@ impl Row {
 
 #[derive(Default)]
 struct Day15 {
-    verbose: bool,
     map_size: usize,
     rows: Vec<Row>,
 }
 
 impl Day15 {
-    fn new(verbose: bool, map_size: usize) -> Self {
+    fn new(map_size: usize) -> Self {
         Self {
-            verbose,
             map_size,
-            rows: vec![Default::default(); (map_size + 1) as usize],
-            ..Default::default()
+            rows: vec![Default::default(); map_size + 1],
         }
     }
     fn clip(&mut self) {
@@ -97,7 +98,7 @@ impl LineStreamHandler for Day15 {
             let dist_to_y = abs_diff(sensor_y, y);
             if distance >= dist_to_y {
                 let remaining = (distance - dist_to_y) as i32;
-                self.rows[y as usize]
+                self.rows[y]
                     .impossibles
                     .insert((sensor.0 - remaining)..=(sensor.0 + remaining));
             }
@@ -105,44 +106,39 @@ impl LineStreamHandler for Day15 {
         if beacon.1 >= 0 && beacon.1 <= self.map_size as i32 {
             self.rows[beacon.1 as usize].beacons.insert(beacon.0);
         }
-        eprint!("{}", '.');
+        trace!("Added sensor at {:?} with beacon at {:?}", sensor, beacon);
 
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        eprintln!();
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mid_y = self.map_size / 2;
-        println!(
-            "[{}] # of impossible positions at mid y: {:?}",
-            SILVER_ANSI,
-            self.rows[mid_y].count_impossibles()
+        let answers = Answers::new().with_silver(
+            "# of impossible positions at mid y",
+            self.rows[mid_y].count_impossibles(),
         );
-        if self.verbose {
-            println!(
-                "[-] Beacon positions at mid y:          {:?}",
-                self.rows[mid_y].beacons
-            );
-            println!(
-                "[-] Impossible positions at mid y:      {:?}",
-                self.rows[mid_y].impossibles
-            );
-        }
-        self.clip();
-        println!(
-            "[{}] Distress tuning frequency:          {:?}",
-            GOLD_ANSI,
-            self.distress_tuning_frequency()
+        debug!("Beacon positions at mid y: {:?}", self.rows[mid_y].beacons);
+        debug!(
+            "Impossible positions at mid y: {:?}",
+            self.rows[mid_y].impossibles
         );
+        self.clip();
+        let frequency = self
+            .distress_tuning_frequency()
+            .ok_or_else(|| AocError::unsolvable("distress beacon not found"))?;
 
-        Ok(())
+        Ok(answers.with_gold("Distress tuning frequency", frequency))
     }
 }
 
-pub fn new(sample: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        15,
-        "Beacon Exclusion Zone",
-        Day15::new(verbose, if sample { SAMPLE_MAP_SIZE } else { MAP_SIZE }),
-    ))
+pub fn new(sample: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day15::new(if sample {
+        SAMPLE_MAP_SIZE
+    } else {
+        MAP_SIZE
+    })))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 15, "Beacon Exclusion Zone", Parts::Both, |args| {
+    new(args.flag("sample"))
+});
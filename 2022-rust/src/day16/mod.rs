@@ -8,8 +8,9 @@ use nom::multi::separated_list1;
 use nom::sequence::{pair, preceded, separated_pair};
 
 use aoc_common_rs::{
+    answers::Answers,
     cc::TwoCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     ord::ProximityMap,
 };
@@ -73,26 +74,24 @@ impl LineStreamHandler for Day16 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.valves.add_virtual_starter()?;
         self.valves.prepare()?;
         let solo = self.valves.max_pressure_release(30, 1)?;
         // With sample input: 1651 (DD 28*20, BB 25*13, JJ 21*21, HH 13*22, EE  9* 3, CC  6* 2)
-        println!(
-            "[{}] Solo max pressure release in 30': {} {:?}",
-            SILVER_ANSI, solo.0, solo.1
-        );
         let duo = self.valves.max_pressure_release(26, 2)?;
         // With sample input: 1707 (DD 24*20, JJ 23*21, HH 19*22, BB 19*21, CC 17* 2, EE 15* 3)
-        println!(
-            "[{}] Duo max pressure release in 26':  {} {:?}",
-            GOLD_ANSI, duo.0, duo.1
-        );
 
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Solo max pressure release in 30'", solo.0)
+            .with_gold("Duo max pressure release in 26'", duo.0)
+            .with_extra("Solo moves", format!("{:?}", solo.1))
+            .with_extra("Duo moves", format!("{:?}", duo.1)))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(16, "Proboscidea Volcanium", Day16::new()))
+    Ok(Day::new(Day16::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 16, "Proboscidea Volcanium", Parts::Both, |_| new());
@@ -4,10 +4,13 @@ use std::error::Error;
 use std::ops::{Deref, DerefMut};
 
 use aoc_common_rs::cc::TwoCC;
+use aoc_common_rs::error::AocError;
 use aoc_common_rs::ord::Top;
 
 use super::valve::{Valve, STARTING_VALVE_ID, VIRTUAL_STARTER_ID};
 
+type Actor = (TwoCC, u16);
+
 #[derive(Clone, Debug, Default)]
 #[repr(transparent)]
 pub struct ValveNetwork(HashMap<TwoCC, Valve>);
@@ -23,7 +26,7 @@ impl ValveNetwork {
 
             Ok(())
         } else {
-            Err(Box::from("starting valve not found"))
+            Err(AocError::invalid_input("starting valve not found").into())
         }
     }
     fn link_neighbors(&mut self, id: TwoCC, unlink_self: bool) -> Result<(), Box<dyn Error>> {
@@ -34,12 +37,16 @@ impl ValveNetwork {
                     if let Some(v1) = self.0.get_mut(id1) {
                         v1.neighbors.insert(*id2, t1 + t2);
                     } else {
-                        return Err(Box::from("lower neighbor valve not found"));
+                        return Err(
+                            AocError::invalid_input("lower neighbor valve not found").into()
+                        );
                     }
                     if let Some(v2) = self.0.get_mut(id2) {
                         v2.neighbors.insert(*id1, t1 + t2);
                     } else {
-                        return Err(Box::from("upper neighbor valve not found"));
+                        return Err(
+                            AocError::invalid_input("upper neighbor valve not found").into()
+                        );
                     }
                 }
             }
@@ -88,7 +95,7 @@ impl ValveNetwork {
         &self,
         t: u16,
         num: usize,
-    ) -> Result<(u32, Vec<(TwoCC, u16)>), Box<dyn Error>> {
+    ) -> Result<(u32, Vec<Actor>), Box<dyn Error>> {
         let mut valves: HashSet<TwoCC> = HashSet::new();
         for id in self.0.keys() {
             valves.insert(*id);
@@ -102,11 +109,11 @@ impl ValveNetwork {
         actors: Vec<(TwoCC, u16)>,
         valves: HashSet<TwoCC>,
         depth: usize,
-    ) -> Result<(u32, Vec<(TwoCC, u16)>), Box<dyn Error>> {
+    ) -> Result<(u32, Vec<Actor>), Box<dyn Error>> {
         // println!("{:indent$} {:?} {:?}", "", ids_and_ts, remaining_ids, indent = depth);
         let mut top_moves: Top<PressureReleaseMove, 12> = Default::default();
         for id in &valves {
-            let valve = self.0.get(&id).unwrap();
+            let valve = self.0.get(id).unwrap();
             let mut mv: PressureReleaseMove = Default::default();
             for (actor, i) in actors.iter().zip(0usize..) {
                 let distance = *valve.neighbors.get(&actor.0).unwrap();
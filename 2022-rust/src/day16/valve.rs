@@ -14,7 +14,6 @@ impl Valve {
         Self {
             flow_rate,
             neighbors,
-            ..Default::default()
         }
     }
     pub fn is_jammed(&self) -> bool {
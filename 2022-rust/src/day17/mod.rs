@@ -3,15 +3,24 @@ use std::collections::HashSet;
 use std::error::Error;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::time::Duration;
 
 use lazy_static::lazy_static;
+use log::{debug, log_enabled, Level};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
+    error::AocError,
+    frames::{FrameRecorder, Rgb},
     line_stream::LineStreamHandler,
     point::Direction2,
+    terrain::Terrain,
 };
 
+const RECORDED_ROWS: usize = 32;
+
 lazy_static! {
     static ref ROCKS: Vec<Vec<u8>> = vec![
         vec![0b0011110],
@@ -22,6 +31,34 @@ lazy_static! {
     ];
 }
 
+#[derive(Clone, Copy, Debug)]
+enum Cell {
+    Air,
+    Rock,
+    Falling,
+    Floor,
+}
+
+impl Cell {
+    fn glyph(&self) -> char {
+        match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Falling => '@',
+            Cell::Floor => '-',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Cell::Air => Rgb(24, 24, 32),
+            Cell::Rock => Rgb(160, 160, 160),
+            Cell::Falling => Rgb(255, 96, 0),
+            Cell::Floor => Rgb(96, 64, 32),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq)]
 struct TowerState {
     tower: Vec<u8>,
@@ -50,19 +87,21 @@ impl TowerState {
             self.cleared_rows += rows_to_clear;
         }
     }
-    fn dump_tower(&self) {
+    fn dump_tower(&self) -> String {
+        let mut out = String::new();
         for row in self.tower.iter().rev() {
-            print!("|");
+            out.push('|');
             for bit in (0..7).rev() {
-                print!("{}", if 0 != (row & (1 << bit)) { '#' } else { '.' });
+                out.push(if 0 != (row & (1 << bit)) { '#' } else { '.' });
             }
-            println!("|");
+            out.push_str("|\n");
         }
         if self.cleared_rows > 0 {
-            println!("|{:^7}|", format!("+{}", self.cleared_rows));
+            out.push_str(&format!("|{:^7}|", format!("+{}", self.cleared_rows)));
         } else {
-            println!("+-------+");
+            out.push_str("+-------+");
         }
+        out
     }
 }
 
@@ -192,9 +231,32 @@ impl Tower {
             Direction2::Up => unimplemented!(),
         }
     }
-    fn dump_tower(&self) {
+    fn dump_tower(&self) -> String {
         self.snapshot().dump_tower()
     }
+    /// The top `rows` rows of the tower with the falling rock, showing cleared
+    /// rows as floor since nothing can reach them any more.
+    fn render(&self, rows: usize) -> Terrain<Cell> {
+        let top = (self.current_y + 4).max(rows - 1) as isize;
+        Terrain::new_with(7, rows, |pt| {
+            let y = top - 1 - pt.1 as isize;
+            let bit = 1 << (6 - pt.0);
+            if y < 0 {
+                return Cell::Floor;
+            }
+            let y = y as usize;
+            let falling = y
+                .checked_sub(self.current_y)
+                .and_then(|i| self.current_rock.get(i));
+            if falling.is_some_and(|row| 0 != row & bit) {
+                Cell::Falling
+            } else if self.tower.get(y).is_some_and(|row| 0 != row & bit) {
+                Cell::Rock
+            } else {
+                Cell::Air
+            }
+        })
+    }
 }
 
 impl Deref for Tower {
@@ -213,16 +275,24 @@ impl DerefMut for Tower {
 
 #[derive(Default)]
 struct Day17 {
-    verbose: bool,
     target_locked_rocks: usize,
+    height: usize,
+    recording: Option<(PathBuf, FrameRecorder<Cell>)>,
 }
 
 impl Day17 {
-    fn new(target_locked_rocks: usize, verbose: bool) -> Self {
+    fn new(target_locked_rocks: usize, record: Option<PathBuf>) -> Self {
         Self {
-            verbose,
             target_locked_rocks,
-            ..Default::default()
+            height: 0,
+            recording: record.map(|path| {
+                (
+                    path,
+                    FrameRecorder::new(Cell::color)
+                        .with_glyph(Cell::glyph)
+                        .with_delay(Duration::from_millis(40)),
+                )
+            }),
         }
     }
 }
@@ -239,9 +309,15 @@ impl LineStreamHandler for Day17 {
                 match ch {
                     '<' => tower.shift_rock(Direction2::Left),
                     '>' => tower.shift_rock(Direction2::Right),
-                    _ => return Err(Box::from("unrecognized char")),
+                    _ => return Err(AocError::invalid_input("unrecognized char").into()),
                 }
+                let locked_rocks = tower.locked_rocks;
                 tower.shift_rock(Direction2::Down);
+                if let Some((_, recorder)) = &mut self.recording {
+                    if tower.locked_rocks > locked_rocks {
+                        recorder.record(&tower.render(RECORDED_ROWS));
+                    }
+                }
             }
             if let Some(first) = snapshots.get(&tower) {
                 let rocks_per_cycle = tower.locked_rocks - first.locked_rocks;
@@ -254,32 +330,34 @@ impl LineStreamHandler for Day17 {
             snapshots.insert(tower.snapshot());
         }
 
-        println!(
-            "[{}] Tower height after {} rocks: {}",
-            if self.target_locked_rocks > 1_000_000 {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.target_locked_rocks,
-            tower.height()
-        );
-        if self.verbose {
-            tower.dump_tower();
+        self.height = tower.height();
+        if log_enabled!(Level::Debug) {
+            debug!("Tower:\n{}", tower.dump_tower());
         }
 
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        if let Some((path, recorder)) = &self.recording {
+            recorder.save(path)?;
+        }
+        Ok(Answers::new().with_part(
+            self.target_locked_rocks > 1_000_000,
+            format!("Tower height after {} rocks", self.target_locked_rocks),
+            self.height,
+        ))
     }
 }
 
-pub fn new(gold: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        17,
-        "Pyroclastic Flow",
-        Day17::new(if gold { 1_000_000_000_000 } else { 2022 }, verbose),
-    ))
+pub fn new(gold: bool, record: Option<PathBuf>) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day17::new(
+        if gold { 1_000_000_000_000 } else { 2022 },
+        record,
+    )))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 17, "Pyroclastic Flow", Parts::Selectable, |args| {
+    new(args.flag("gold"), args.value(Param::RECORD.name)?)
+})
+.with_params(&[Param::RECORD]);
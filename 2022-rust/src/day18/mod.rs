@@ -5,7 +5,8 @@ use nom::character::complete::{char, i16};
 use nom::combinator::map;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     point::{Direction3, Point3},
 };
@@ -96,22 +97,15 @@ impl LineStreamHandler for Day18 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Total surface area: {}",
-            SILVER_ANSI,
-            self.total_surface_area()
-        );
-        println!(
-            "[{}] Outer surface area: {}",
-            GOLD_ANSI,
-            self.outer_surface_area()
-        );
-
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Total surface area", self.total_surface_area())
+            .with_gold("Outer surface area", self.outer_surface_area()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(18, "Boiling Boulders", Day18::new()))
+    Ok(Day::new(Day18::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 18, "Boiling Boulders", Parts::Both, |_| new());
@@ -1,6 +1,10 @@
 use std::error::Error;
 
-use aoc_common_rs::{day::Day, line_stream::LineStreamHandler};
+use aoc_common_rs::{
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    line_stream::LineStreamHandler,
+};
 
 #[derive(Default)]
 struct Day19 {}
@@ -16,11 +20,13 @@ impl LineStreamHandler for Day19 {
         todo!()
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         todo!()
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(19, "Not Enough Minerals", Day19::new()))
+    Ok(Day::new(Day19::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 19, "Not Enough Minerals", Parts::Unsolved, |_| new());
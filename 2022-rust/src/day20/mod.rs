@@ -4,7 +4,9 @@ use std::error::Error;
 use nom::character::complete::i64;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -85,7 +87,7 @@ impl LineStreamHandler for Day20 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if !self.nums.is_empty() {
             let last = self.nums.len() - 1;
             self.next.insert(last, 0);
@@ -94,7 +96,7 @@ impl LineStreamHandler for Day20 {
         if let Some(zero) = self.nums.iter().position(|num| *num == 0) {
             self.zero = zero;
         } else {
-            return Err(Box::from("0 not found in list"));
+            return Err(AocError::invalid_input("0 not found in list").into());
         }
         for _ in 0..self.rounds {
             self.mix();
@@ -102,31 +104,34 @@ impl LineStreamHandler for Day20 {
         let x = self.nth_after(self.zero, 1000);
         let y = self.nth_after(x, 1000);
         let z = self.nth_after(y, 1000);
-        println!(
-            "[{}] Sum of coordinates: {}",
-            if self.key == 1 {
-                SILVER_ANSI
-            } else {
-                GOLD_ANSI
-            },
-            self.nums[x] + self.nums[y] + self.nums[z]
-        );
-        println!(
-            "[-] X = {}, Y = {}, Z = {}",
-            self.nums[x], self.nums[y], self.nums[z]
-        );
 
-        Ok(())
+        Ok(Answers::new()
+            .with_part(
+                self.key != 1,
+                "Sum of coordinates",
+                self.nums[x] + self.nums[y] + self.nums[z],
+            )
+            .with_extra(
+                "Coordinates",
+                format!(
+                    "X = {}, Y = {}, Z = {}",
+                    self.nums[x], self.nums[y], self.nums[z]
+                ),
+            ))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        20,
-        "Grove Positioning System",
-        Day20::new(
-            if gold { 811_589_153 } else { 1 },
-            if gold { 10 } else { 1 },
-        ),
-    ))
+    Ok(Day::new(Day20::new(
+        if gold { 811_589_153 } else { 1 },
+        if gold { 10 } else { 1 },
+    )))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2022,
+    20,
+    "Grove Positioning System",
+    Parts::Selectable,
+    |args| new(args.flag("gold")),
+);
@@ -8,8 +8,10 @@ use nom::error::Error as NomError;
 use nom::sequence::separated_pair;
 
 use aoc_common_rs::{
+    answers::Answers,
     cc::FourCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -46,7 +48,7 @@ impl LineStreamHandler for Day21 {
                 four_cc(),
                 tag(": "),
                 alt((
-                    map(i64, |num| Operation::Const(num)),
+                    map(i64, Operation::Const),
                     map(
                         (
                             four_cc(),
@@ -71,38 +73,39 @@ impl LineStreamHandler for Day21 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if self.gold {
             self.monkeys.remove(&HUMAN);
-            if let Some(_) = self.monkeys.resolve(ROOT_MONKEY) {
-                return Err(Box::from("root doesn't depend on you"));
-            } else {
-                let root_op = self.monkeys[&ROOT_MONKEY].to_owned();
-                if let Some((left_id, right_id)) = root_op.ops() {
-                    if !Operation::Sub(left_id, right_id).assert(0, &mut self.monkeys) {
-                        return Err(Box::from("cannot assert equality of root's operands"));
-                    }
-                } else {
-                    return Err(Box::from("root has no operands"));
-                }
-                if let Some(num) = self.monkeys.resolve(HUMAN) {
-                    println!("[{}] You shall yell {}", GOLD_ANSI, num);
-                } else {
-                    return Err(Box::from("can't determine what you shall yell"));
+            if self.monkeys.resolve(ROOT_MONKEY).is_some() {
+                return Err(AocError::unsolvable("root doesn't depend on you").into());
+            }
+            let root_op = self.monkeys[&ROOT_MONKEY].to_owned();
+            if let Some((left_id, right_id)) = root_op.ops() {
+                if !Operation::Sub(left_id, right_id).assert(0, &mut self.monkeys) {
+                    return Err(
+                        AocError::unsolvable("cannot assert equality of root's operands").into(),
+                    );
                 }
+            } else {
+                return Err(AocError::unsolvable("root has no operands").into());
             }
-        } else {
-            if let Some(num) = self.monkeys.resolve(ROOT_MONKEY) {
-                println!("[{}] Root will yell {}", SILVER_ANSI, num);
+            if let Some(num) = self.monkeys.resolve(HUMAN) {
+                Ok(Answers::new().with_gold("You shall yell", num))
             } else {
-                return Err(Box::from("can't determine what root will yell"));
+                Err(AocError::unsolvable("can't determine what you shall yell").into())
             }
+        } else if let Some(num) = self.monkeys.resolve(ROOT_MONKEY) {
+            Ok(Answers::new().with_silver("Root will yell", num))
+        } else {
+            Err(AocError::unsolvable("can't determine what root will yell").into())
         }
-
-        Ok(())
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(21, "Monkey Math", Day21::new(gold)))
+    Ok(Day::new(Day21::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 21, "Monkey Math", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -1,5 +1,6 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
 use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 use nom::branch::alt;
 use nom::character::complete::{char, u32};
@@ -7,28 +8,28 @@ use nom::combinator::map;
 use nom::multi::many0;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandlerOnce},
     math::gcd,
     point::{Direction2, Direction3, Point2},
+    stepper::{self, Steppable},
+    terrain::Terrain,
 };
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 struct Chunk {
     data: Vec<Vec<bool>>,
     neighbors: HashMap<Direction2, ChunkNeighbor>,
 }
 
 impl Chunk {
-    fn new() -> Self {
-        Default::default()
-    }
     fn is_wall(&self, pos: Point2<usize>) -> bool {
         self.data[pos.1 - 1][pos.0 - 1]
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct ChunkNeighbor {
     chunk: Point2<usize>,
     edge: Direction2,
@@ -45,23 +46,29 @@ impl ChunkNeighbor {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Day22 {
     fold_as_cube: bool,
+    stepper: bool,
     chunk_size: Option<usize>,
     height: usize,
     chunks: HashMap<Point2<usize>, Chunk>,
     position: (Point2<usize>, Point2<usize>, Direction2),
+    moves: Vec<Move>,
+    next_move: usize,
 }
 
 impl Day22 {
-    fn new(fold_as_cube: bool) -> Self {
+    fn new(fold_as_cube: bool, stepper: bool) -> Self {
         Self {
             fold_as_cube,
+            stepper,
             chunk_size: None,
             height: 0,
             chunks: HashMap::new(),
             position: (Point2(0, 0), Point2(0, 0), Direction2::Right),
+            moves: Vec::new(),
+            next_move: 0,
         }
     }
     fn bind_chunks(
@@ -84,13 +91,13 @@ impl Day22 {
             .insert(rev_dir, ChunkNeighbor::new(pos, dir, reverse));
     }
     fn fold(&mut self) {
-        let chunk_keys = self.chunks.keys().map(|pt| *pt).collect::<HashSet<_>>();
+        let chunk_keys = self.chunks.keys().copied().collect::<HashSet<_>>();
         for pos in chunk_keys.iter() {
             for dir in Direction2::all() {
                 let pos_n = pos.next_towards(dir);
                 if chunk_keys.contains(&pos_n) {
                     self.chunks
-                        .get_mut(&pos)
+                        .get_mut(pos)
                         .unwrap()
                         .neighbors
                         .insert(dir, ChunkNeighbor::new(pos_n, -dir, false));
@@ -108,9 +115,9 @@ impl Day22 {
                 let basis = bases.get(&pos).unwrap().to_owned();
                 for dir in Direction2::all() {
                     if let Some(neighbor) = self.chunks.get(&pos).unwrap().neighbors.get(&dir) {
-                        if !bases.contains_key(&neighbor.chunk) {
+                        if let Entry::Vacant(e) = bases.entry(neighbor.chunk) {
                             let basis_n = neighbor_basis(basis, dir).unwrap();
-                            bases.insert(neighbor.chunk, basis_n);
+                            e.insert(basis_n);
                             faces.insert(basis_n.0.cross(basis_n.1).unwrap(), neighbor.chunk);
                             queue.push_back(neighbor.chunk);
                         }
@@ -118,7 +125,7 @@ impl Day22 {
                 }
             }
             for pos in chunk_keys.iter() {
-                let basis = bases.get(&pos).unwrap().to_owned();
+                let basis = bases.get(pos).unwrap().to_owned();
                 for dir in Direction2::all() {
                     if !self.chunks.get(pos).unwrap().neighbors.contains_key(&dir) {
                         let basis_n = neighbor_basis(basis, dir).unwrap();
@@ -263,6 +270,7 @@ fn direction_along_edge(basis: (Direction3, Direction3), edge: Direction2) -> Di
     }
 }
 
+#[derive(Clone, Copy, Debug)]
 enum Move {
     Walk(usize),
     TurnCounterclockwise,
@@ -299,9 +307,7 @@ impl LineStreamHandlerOnce for Day22Map {
             let y = state.height / chunk_size;
             let xs = (spaces / chunk_size)..(line.len() / chunk_size);
             for x in xs {
-                if !state.chunks.contains_key(&Point2(x + 1, y + 1)) {
-                    state.chunks.insert(Point2(x + 1, y + 1), Chunk::new());
-                }
+                state.chunks.entry(Point2(x + 1, y + 1)).or_default();
                 let chunk = state.chunks.get_mut(&Point2(x + 1, y + 1)).unwrap();
                 let mut row = Vec::new();
                 for ch in line[(x * chunk_size)..((x + 1) * chunk_size)].chars() {
@@ -315,7 +321,7 @@ impl LineStreamHandlerOnce for Day22Map {
         Ok(self)
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         unreachable!()
     }
 }
@@ -333,30 +339,92 @@ impl LineStreamHandlerOnce for Day22Moves {
                 map(char('R'), |_| Move::TurnClockwise),
             ))),
         )?;
-        let state = &mut self.0;
-        for m in moves {
-            state.do_move(m);
-        }
+        self.0.moves.extend(moves);
 
         Ok(self)
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        let state = self.0;
-        println!(
-            "[{}] Final password: {}",
-            if state.fold_as_cube {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            state.password()
-        );
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let mut state = self.0;
+        if state.stepper {
+            stepper::interact(state.clone())?;
+        }
+        while state.step() {}
+
+        Ok(Answers::new().with_part(state.fold_as_cube, "Final password", state.password()))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+    Facing(Direction2),
+}
 
-        Ok(())
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_char(match self {
+            Tile::Void => ' ',
+            Tile::Open => '.',
+            Tile::Wall => '#',
+            Tile::Facing(Direction2::Right) => '>',
+            Tile::Facing(Direction2::Down) => 'v',
+            Tile::Facing(Direction2::Left) => '<',
+            Tile::Facing(Direction2::Up) => '^',
+        })
     }
 }
 
-pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new_once(22, "Monkey Map", Day22Map(Day22::new(gold))))
+impl Steppable for Day22 {
+    type Cell = Tile;
+
+    fn step(&mut self) -> bool {
+        let Some(&m) = self.moves.get(self.next_move) else {
+            return false;
+        };
+        self.next_move += 1;
+        self.do_move(m);
+        true
+    }
+
+    fn render(&self) -> Terrain<Tile> {
+        let chunk_size = self.chunk_size.unwrap_or(1);
+        let width = self.chunks.keys().map(|chunk| chunk.0).max().unwrap_or(0) * chunk_size;
+        let (chunk, pos, direction) = self.position;
+        Terrain::new_with(width, self.height, |pt| {
+            let chunk_pt = Point2(pt.0 / chunk_size + 1, pt.1 / chunk_size + 1);
+            let pos_pt = Point2(pt.0 % chunk_size + 1, pt.1 % chunk_size + 1);
+            match self.chunks.get(&chunk_pt) {
+                _ if chunk_pt == chunk && pos_pt == pos => Tile::Facing(direction),
+                Some(chunk) if chunk.is_wall(pos_pt) => Tile::Wall,
+                Some(_) => Tile::Open,
+                None => Tile::Void,
+            }
+        })
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "move {}/{}{}, facing {:?}, password {}",
+            self.next_move,
+            self.moves.len(),
+            match self.next_move.checked_sub(1) {
+                Some(last) => format!(" {:?}", self.moves[last]),
+                None => String::new(),
+            },
+            self.position.2,
+            self.password()
+        )
+    }
 }
+
+pub fn new(gold: bool, stepper: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new_once(Day22Map(Day22::new(gold, stepper))))
+}
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 22, "Monkey Map", Parts::Selectable, |args| {
+    new(args.flag("gold"), args.flag(Param::STEP.name))
+})
+.with_params(&[Param::STEP]);
@@ -1,6 +1,10 @@
 use std::error::Error;
 
-use aoc_common_rs::{day::Day, line_stream::LineStreamHandler};
+use aoc_common_rs::{
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    line_stream::LineStreamHandler,
+};
 
 #[derive(Default)]
 struct Day23 {}
@@ -16,11 +20,13 @@ impl LineStreamHandler for Day23 {
         todo!()
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         todo!()
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(23, "Unstable Diffusion", Day23::new()))
+    Ok(Day::new(Day23::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 23, "Unstable Diffusion", Parts::Unsolved, |_| new());
@@ -2,7 +2,9 @@ use std::collections::HashSet;
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::LineStreamHandler,
     point::{Direction2, Point2},
 };
@@ -51,10 +53,7 @@ impl Day24 {
     fn has_blizzard_at(&self, pos: Point2<usize>) -> bool {
         self.blizzards.iter().any(|bliz| bliz.position == pos)
     }
-    fn neighbors<'a>(
-        self: &'a Self,
-        pt: Point2<usize>,
-    ) -> impl Iterator<Item = Point2<usize>> + 'a {
+    fn neighbors<'a>(&'a self, pt: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + 'a {
         Direction2::all().filter_map(move |dir| {
             if match dir {
                 Direction2::Right => pt.0 < self.end.0 && pt.1 > 0,
@@ -122,44 +121,41 @@ impl LineStreamHandler for Day24 {
                 '^' => {
                     self.blizzards.push(Blizzard::new(Direction2::Up, pos));
                 }
-                '#' => {
-                    if i == 1 {
-                        is_end_line = true;
-                    }
+                '#' if i == 1 => {
+                    is_end_line = true;
                 }
-                '.' => {
-                    if is_end_line {
-                        self.end = pos;
-                    }
+                '.' if is_end_line => {
+                    self.end = pos;
                 }
                 _ => {}
             }
         }
-        self.height = self.height + 1;
+        self.height += 1;
 
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if let Some(time) = self.time_forwards() {
-            println!("[{}] Time to traverse: {}", SILVER_ANSI, time);
             if let Some(time2) = self.time_backwards() {
                 if let Some(time3) = self.time_forwards() {
-                    println!("[{}] Total time:       {}", GOLD_ANSI, time + time2 + time3);
+                    Ok(Answers::new()
+                        .with_silver("Time to traverse", time)
+                        .with_gold("Total time", time + time2 + time3))
                 } else {
-                    return Err(Box::from("cannot traverse again"));
+                    Err(AocError::unsolvable("cannot traverse again").into())
                 }
             } else {
-                return Err(Box::from("cannot traverse back"));
+                Err(AocError::unsolvable("cannot traverse back").into())
             }
         } else {
-            return Err(Box::from("cannot traverse"));
+            Err(AocError::unsolvable("cannot traverse").into())
         }
-
-        Ok(())
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(24, "Blizzard Basin", Day24::new()))
+    Ok(Day::new(Day24::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 24, "Blizzard Basin", Parts::Both, |_| new());
@@ -1,7 +1,8 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
 };
 
@@ -28,17 +29,13 @@ impl LineStreamHandler for Day25 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of fuel requirements: {}",
-            SILVER_ANSI,
-            Snafu(self.sum)
-        );
-
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_silver("Sum of fuel requirements", Snafu(self.sum).to_string()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(25, "Full of Hot Air", Day25::new()))
+    Ok(Day::new(Day25::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2022, 25, "Full of Hot Air", Parts::SilverOnly, |_| new());
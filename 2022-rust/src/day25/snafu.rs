@@ -2,6 +2,8 @@ use std::collections::VecDeque;
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use aoc_common_rs::error::AocError;
+
 #[derive(Debug)]
 pub struct Snafu(pub u64);
 
@@ -18,7 +20,7 @@ impl TryFrom<&str> for Snafu {
                     '0' => 2,
                     '1' => 3,
                     '2' => 4,
-                    _ => return Err(Box::from("invalid SNAFU character")),
+                    _ => return Err(AocError::invalid_input("invalid SNAFU character").into()),
                 }
                 - 2;
         }
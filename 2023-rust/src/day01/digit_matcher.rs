@@ -75,8 +75,8 @@ enum State {
 
 impl State {
     fn update(self, ch: char) -> (Self, Digit) {
-        if ch.is_digit(10) {
-            return (Self::Initial, Digit::Numeric((ch as u8) - ('0' as u8)));
+        if ch.is_ascii_digit() {
+            return (Self::Initial, Digit::Numeric((ch as u8) - b'0'));
         }
 
         match (self, ch) {
@@ -1,7 +1,9 @@
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
 };
+use log::debug;
 use std::error::Error;
 
 mod digit_matcher;
@@ -10,15 +12,13 @@ use digit_matcher::DigitMatcher;
 
 struct Day1 {
     matcher: DigitMatcher,
-    verbose: bool,
     calibration_sum: u32,
 }
 
 impl Day1 {
-    fn new(gold: bool, verbose: bool) -> Self {
+    fn new(gold: bool) -> Self {
         Self {
             matcher: DigitMatcher::new(gold),
-            verbose,
             calibration_sum: 0,
         }
     }
@@ -36,27 +36,24 @@ impl LineStreamHandler for Day1 {
             }
         }
         let calibration_value = first_digit.unwrap() * 10 + last_digit.unwrap();
-        if self.verbose {
-            println!("[-] {} | {}", calibration_value, line);
-        }
+        debug!("{} | {}", calibration_value, line);
         self.calibration_sum += calibration_value as u32;
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn std::error::Error>> {
-        println!(
-            "[{}] Sum of calibration values: {}",
-            if self.matcher.allow_spelled_out {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.calibration_sum
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn std::error::Error>> {
+        Ok(Answers::new().with_part(
+            self.matcher.allow_spelled_out,
+            "Sum of calibration values",
+            self.calibration_sum,
+        ))
     }
 }
 
-pub fn new(gold: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(1, "Trebuchet?!", Day1::new(gold, verbose)))
+pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day1::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 1, "Trebuchet?!", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
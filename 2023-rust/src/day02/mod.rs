@@ -10,7 +10,8 @@ use nom::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -124,19 +125,15 @@ impl LineStreamHandler for Day2 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of IDs of possible games: {}",
-            SILVER_ANSI, self.sum_of_possible
-        );
-        println!(
-            "[{}] Sum of minimal set powers:    {}",
-            GOLD_ANSI, self.sum_of_powers
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Sum of IDs of possible games", self.sum_of_possible)
+            .with_gold("Sum of minimal set powers", self.sum_of_powers))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(2, "Cube Conundrum", Day2::new()))
+    Ok(Day::new(Day2::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 2, "Cube Conundrum", Parts::Both, |_| new());
@@ -1,7 +1,8 @@
 use std::{error::Error, mem::replace};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
 };
 
@@ -37,20 +38,16 @@ impl LineStreamHandler for Day3 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.sum_of_ratios += self.previous_line.into_gear_ratios();
-        println!(
-            "[{}] Sum of part numbers: {}",
-            SILVER_ANSI, self.sum_of_parts
-        );
-        println!(
-            "[{}] Sum of gear ratios:  {}",
-            GOLD_ANSI, self.sum_of_ratios
-        );
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Sum of part numbers", self.sum_of_parts)
+            .with_gold("Sum of gear ratios", self.sum_of_ratios))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(3, "Gear Ratios", Day3::new()))
+    Ok(Day::new(Day3::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 3, "Gear Ratios", Parts::Both, |_| new());
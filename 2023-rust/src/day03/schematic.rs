@@ -14,7 +14,7 @@ impl Token {
         matches!(self, Token::None)
     }
     fn new(ch: char) -> Self {
-        if ch.is_digit(10) {
+        if ch.is_ascii_digit() {
             Self::Number((ch as u32) - ('0' as u32))
         } else if ch == '.' {
             Self::None
@@ -25,7 +25,7 @@ impl Token {
     fn append(self, ch: char) -> Self {
         match self {
             Self::Number(num) => {
-                if ch.is_digit(10) {
+                if ch.is_ascii_digit() {
                     Self::Number(num * 10 + (ch as u32) - ('0' as u32))
                 } else {
                     Self::None
@@ -73,7 +73,7 @@ impl Symbol {
 
 fn symbols_around_range(symbols: &[Symbol], position: &Range<usize>) -> Range<usize> {
     binary_search_range_by_key(
-        &symbols,
+        symbols,
         &position.start.saturating_sub(1),
         &position.end,
         |sym| sym.position,
@@ -81,7 +81,7 @@ fn symbols_around_range(symbols: &[Symbol], position: &Range<usize>) -> Range<us
 }
 
 fn symbols_around<'a>(symbols: &'a [Symbol], position: &Range<usize>) -> &'a [Symbol] {
-    let range = symbols_around_range(&symbols, position);
+    let range = symbols_around_range(symbols, position);
     if range.start > range.end {
         &[]
     } else {
@@ -90,7 +90,7 @@ fn symbols_around<'a>(symbols: &'a [Symbol], position: &Range<usize>) -> &'a [Sy
 }
 
 fn symbols_around_mut<'a>(symbols: &'a mut [Symbol], position: &Range<usize>) -> &'a mut [Symbol] {
-    let range = symbols_around_range(&symbols, position);
+    let range = symbols_around_range(symbols, position);
     if range.start > range.end {
         &mut []
     } else {
@@ -142,7 +142,7 @@ impl Line {
         let mut result = 0;
         for number in &other.numbers {
             let symbols = symbols_around(&self.symbols, &number.position);
-            if symbols.len() > 0 {
+            if !symbols.is_empty() {
                 result += number.value;
             }
         }
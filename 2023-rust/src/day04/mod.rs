@@ -11,7 +11,8 @@ use nom::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -41,7 +42,7 @@ impl LineStreamHandler for Day4 {
                 separated_pair(
                     fold_many1(
                         terminated(u32, multispace0),
-                        || HashSet::new(),
+                        HashSet::new,
                         |mut set, num| {
                             set.insert(num);
                             set
@@ -50,7 +51,7 @@ impl LineStreamHandler for Day4 {
                     terminated(tag("|"), multispace0),
                     fold_many1(
                         terminated(u32, multispace0),
-                        || HashSet::new(),
+                        HashSet::new,
                         |mut set, num| {
                             set.insert(num);
                             set
@@ -80,19 +81,19 @@ impl LineStreamHandler for Day4 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        if self.gold {
-            println!(
-                "[{}] Total number of scratchcards: {}",
-                GOLD_ANSI, self.score
-            );
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(if self.gold {
+            Answers::new().with_gold("Total number of scratchcards", self.score)
         } else {
-            println!("[{}] Total score: {}", SILVER_ANSI, self.score);
-        }
-        Ok(())
+            Answers::new().with_silver("Total score", self.score)
+        })
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(4, "Scratchcards", Day4::new(gold)))
+    Ok(Day::new(Day4::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 4, "Scratchcards", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -10,7 +10,8 @@ use nom::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -163,7 +164,7 @@ impl LineStreamHandler for Day5 {
             alt((
                 map(
                     preceded(tag("seeds: "), separated_list1(tag(" "), u64)),
-                    |vec| Line::Seeds(vec),
+                    Line::Seeds,
                 ),
                 map(
                     terminated(separated_pair(alpha1, tag("-to-"), alpha1), tag(" map:")),
@@ -207,24 +208,23 @@ impl LineStreamHandler for Day5 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut lowest_id = u64::MAX;
         for id in self.ids {
             lowest_id = lowest_id.min(id.value.start);
         }
-        println!(
-            "[{}] Lowest location: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            lowest_id
-        );
-        Ok(())
+        Ok(Answers::new().with_part(self.gold, "Lowest location", lowest_id))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        5,
-        "If You Give A Seed A Fertilizer",
-        Day5::new(gold),
-    ))
+    Ok(Day::new(Day5::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2023,
+    5,
+    "If You Give A Seed A Fertilizer",
+    Parts::Selectable,
+    |args| new(args.flag("gold")),
+);
@@ -10,7 +10,8 @@ use nom::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -68,10 +69,11 @@ impl LineStreamHandler for Day6 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn std::error::Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn std::error::Error>> {
+        let mut answers = Answers::new();
         let mut product = 1u64;
-        for ((_, time), (_, distance)) in self.times.into_iter().zip(self.distances.into_iter()) {
-            println!("[-] Time: {} - Distance: {}", time, distance);
+        for ((_, time), (_, distance)) in self.times.into_iter().zip(self.distances) {
+            answers.push_extra("Time", format!("{} - Distance: {}", time, distance));
             let f_time = time as f64;
             let f_distance = distance as f64;
             let f_min = (f_time - (f_time * f_time - 4.0 * f_distance).sqrt()) / 2.0;
@@ -79,15 +81,14 @@ impl LineStreamHandler for Day6 {
             let ways_to_beat = (time / 2 - min) * 2 + time % 2 + 1;
             product *= ways_to_beat;
         }
-        println!(
-            "[{}] Ways to beat records: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            product
-        );
-        Ok(())
+        Ok(answers.with_part(self.gold, "Ways to beat records", product))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(6, "Wait For It", Day6::new(gold)))
+    Ok(Day::new(Day6::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 6, "Wait For It", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -7,7 +7,9 @@ use nom::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
     unwrap_either,
 };
@@ -58,7 +60,7 @@ impl TryFrom<char> for Card {
             '4' => Ok(Self::Four),
             '3' => Ok(Self::Three),
             '2' => Ok(Self::Two),
-            _ => Err("value".into()),
+            _ => Err(AocError::invalid_input("value").into()),
         }
     }
 }
@@ -370,21 +372,20 @@ impl LineStreamHandler for Day7 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut score = 0;
         let num_hands = self.hands.len();
         for ((_, bid), rank) in self.hands.into_iter().zip((0..num_hands).rev()) {
             score += bid * (rank + 1) as u32;
         }
-        println!(
-            "[{}] Total winnings: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            score
-        );
-        Ok(())
+        Ok(Answers::new().with_part(self.gold, "Total winnings", score))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(7, "Camel Cards", Day7::new(gold)))
+    Ok(Day::new(Day7::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 7, "Camel Cards", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -10,8 +10,10 @@ use nom::{
 };
 
 use aoc_common_rs::{
+    answers::Answers,
     cc::ThreeCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, take_fixed, LineStreamHandler},
     math::lcm,
 };
@@ -70,7 +72,7 @@ impl Day8 {
                     break;
                 }
             } else {
-                return Err("node not found".into());
+                return Err(AocError::invalid_input("node not found").into());
             }
         }
         Ok(moves)
@@ -106,7 +108,7 @@ impl LineStreamHandler for Day8 {
                         value(Direction::Left, char('L')),
                         value(Direction::Right, char('R')),
                     ))),
-                    |directions| Line::Directions(directions),
+                    Line::Directions,
                 ),
             )),
         )? {
@@ -121,7 +123,7 @@ impl LineStreamHandler for Day8 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut moves;
         if self.gold {
             moves = 1;
@@ -136,15 +138,14 @@ impl LineStreamHandler for Day8 {
         } else {
             moves = self.moves(START_POSITION, |position| position == END_POSITION)?;
         }
-        println!(
-            "[{}] Number of moves: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            moves
-        );
-        Ok(())
+        Ok(Answers::new().with_part(self.gold, "Number of moves", moves))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(8, "Haunted Wasteland", Day8::new(gold)))
+    Ok(Day::new(Day8::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 8, "Haunted Wasteland", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -6,7 +6,9 @@ use nom::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -53,20 +55,19 @@ impl LineStreamHandler for Day9 {
             self.sum += extrapolated;
             Ok(())
         } else {
-            Err("could not extrapolate".into())
+            Err(AocError::unsolvable("could not extrapolate").into())
         }
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of extrapolated values: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            self.sum
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.gold, "Sum of extrapolated values", self.sum))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(9, "Mirage Maintenance", Day9::new(gold)))
+    Ok(Day::new(Day9::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 9, "Mirage Maintenance", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
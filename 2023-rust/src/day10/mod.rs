@@ -1,7 +1,9 @@
 use std::{collections::HashSet, error::Error};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::LineStreamHandler,
     point::{Direction2, Point2},
     terrain::Terrain,
@@ -40,7 +42,7 @@ impl Pipe {
             (true, false, true) => Ok(Self::NorthWest),
             (false, false, true) => Ok(Self::SouthWest),
             (false, true, false) => Ok(Self::SouthEast),
-            _ => Err("cannot determine pipe from links".into()),
+            _ => Err(AocError::invalid_input("cannot determine pipe from links").into()),
         }
     }
     fn any_direction(self) -> Option<Direction2> {
@@ -83,7 +85,7 @@ impl TryFrom<char> for Pipe {
             '7' => Ok(Self::SouthWest),
             'F' => Ok(Self::SouthEast),
             'S' => Ok(Self::Start),
-            _ => Err("unrecognized character".into()),
+            _ => Err(AocError::invalid_input("unrecognized character").into()),
         }
     }
 }
@@ -104,19 +106,19 @@ impl Day10 {
         if let Some(start) = self.start {
             let mut direction = self.terrain[start]
                 .any_direction()
-                .ok_or_else(|| Box::<dyn Error>::from("no start direction"))?;
+                .ok_or_else(|| AocError::invalid_input("no start direction"))?;
             let mut main_loop = vec![start];
             let mut current = start.next_towards(direction);
             while current != start {
                 direction = self.terrain[current]
                     .direction_through(direction)
-                    .ok_or_else(|| Box::<dyn Error>::from("dead end"))?;
+                    .ok_or_else(|| AocError::invalid_input("dead end"))?;
                 main_loop.push(current);
                 current = current.next_towards(direction);
             }
             Ok(main_loop)
         } else {
-            Err("no start position".into())
+            Err(AocError::invalid_input("no start position").into())
         }
     }
     fn enclosed_by(&self, perimeter: HashSet<Point2<usize>>) -> HashSet<Point2<usize>> {
@@ -125,7 +127,7 @@ impl Day10 {
         let mut inbetweens = Terrain::new_with(width + 1, height + 1, |_| true);
         inbetweens[Point2(0, 0)] = false;
         let mut next: Vec<Point2<usize>> = vec![Point2(0, 0)];
-        while next.len() > 0 {
+        while !next.is_empty() {
             let current = next;
             next = Vec::new();
             for point in current.into_iter() {
@@ -185,9 +187,9 @@ impl LineStreamHandler for Day10 {
             .collect::<Result<Vec<_>, _>>()?;
         if let Some(start) = row.iter().position(|dir| matches!(*dir, Pipe::Start)) {
             if self.start.is_some() {
-                return Err("multiple start positions".into());
+                return Err(AocError::invalid_input("multiple start positions").into());
             }
-            let start_row = self.terrain.len();
+            let start_row = self.terrain.height();
             self.start = Some(Point2(start, start_row));
             let links_to_north = start_row > 0
                 && self.terrain[Point2(start, start_row - 1)].links_to(Direction2::Down);
@@ -195,27 +197,22 @@ impl LineStreamHandler for Day10 {
             let links_to_east = start + 1 < row.len() && row[start + 1].links_to(Direction2::Left);
             row[start] = Pipe::from_links(links_to_north, links_to_east, links_to_west)?;
         }
-        self.terrain.push_row(row);
+        self.terrain.push_row(row)?;
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let main_loop = self.main_loop()?;
-        println!(
-            "[{}] Farthest distance from start: {}",
-            SILVER_ANSI,
-            main_loop.len() / 2
-        );
+        let farthest = main_loop.len() / 2;
         let inside = self.enclosed_by(main_loop.into_iter().collect());
-        println!(
-            "[{}] Tiles enclosed by main loop:  {}",
-            GOLD_ANSI,
-            inside.len()
-        );
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Farthest distance from start", farthest)
+            .with_gold("Tiles enclosed by main loop", inside.len()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(10, "Pipe Maze", Day10::new()))
+    Ok(Day::new(Day10::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 10, "Pipe Maze", Parts::Both, |_| new());
@@ -5,7 +5,8 @@ use std::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
     ord::binary_search_range,
     point::Point2,
@@ -79,7 +80,7 @@ impl LineStreamHandler for Day11 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut sum_of_fixed_distances = 0usize;
         let mut sum_of_expandable_distances = 0usize;
         for (galaxy1, i) in self.galaxies.iter().zip(0usize..) {
@@ -89,32 +90,19 @@ impl LineStreamHandler for Day11 {
                 sum_of_expandable_distances += expandable_distance;
             }
         }
-        println!(
-            "[-] Sum of base distances:    {}",
-            sum_of_fixed_distances + sum_of_expandable_distances
-        );
-        println!(
-            "[{}] Sum of distances (E×2):   {}",
-            SILVER_ANSI,
-            sum_of_fixed_distances + sum_of_expandable_distances * 2
-        );
-        println!(
-            "[-] Sum of distances (E×10):  {}",
-            sum_of_fixed_distances + sum_of_expandable_distances * 10
-        );
-        println!(
-            "[-] Sum of distances (E×100): {}",
-            sum_of_fixed_distances + sum_of_expandable_distances * 100
-        );
-        println!(
-            "[{}] Sum of distances (E×1M):  {}",
-            GOLD_ANSI,
-            sum_of_fixed_distances + sum_of_expandable_distances * 1_000_000
-        );
-        Ok(())
+        let expanded =
+            |factor: usize| sum_of_fixed_distances + sum_of_expandable_distances * factor;
+        Ok(Answers::new()
+            .with_silver("Sum of distances (E×2)", expanded(2))
+            .with_gold("Sum of distances (E×1M)", expanded(1_000_000))
+            .with_extra("Sum of base distances", expanded(1))
+            .with_extra("Sum of distances (E×10)", expanded(10))
+            .with_extra("Sum of distances (E×100)", expanded(100)))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(11, "Cosmic Expansion", Day11::new()))
+    Ok(Day::new(Day11::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 11, "Cosmic Expansion", Parts::Both, |_| new());
@@ -1,7 +1,8 @@
 use std::{collections::HashMap, error::Error, hash::Hash, ops::Deref};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 use itertools::Itertools;
@@ -189,7 +190,7 @@ fn trim_fixed_damaged<'a, 'b>(
         }
         let num_states = states.len();
         if num_states < *run {
-            return Err("not enough states".into());
+            return Err("not enough states");
         }
         match states.try_match_at_start(*run) {
             Some(rest) => {
@@ -197,7 +198,7 @@ fn trim_fixed_damaged<'a, 'b>(
                 damaged_runs = &damaged_runs[1..];
             }
             None => {
-                return Err("inconsistent states".into());
+                return Err("inconsistent states");
             }
         }
     }
@@ -207,7 +208,7 @@ fn trim_fixed_damaged<'a, 'b>(
         }
         let num_states = states.len();
         if num_states < *run {
-            return Err("not enough states".into());
+            return Err("not enough states");
         }
         match states.try_match_at_end(*run) {
             Some(rest) => {
@@ -215,7 +216,7 @@ fn trim_fixed_damaged<'a, 'b>(
                 damaged_runs = &damaged_runs[..(damaged_runs.len() - 1)];
             }
             None => {
-                return Err("inconsistent states".into());
+                return Err("inconsistent states");
             }
         }
     }
@@ -247,27 +248,27 @@ fn arrangements<'a, 'b>(
 ) -> Result<usize, &'static str> {
     let sum_of_runs: usize = damaged_runs.iter().sum();
     if states.num_damaged + states.num_unknown < sum_of_runs {
-        return Err("not enough damaged/unknowns".into());
+        return Err("not enough damaged/unknowns");
     }
     if states.num_damaged > sum_of_runs {
-        return Err("too many damaged".into());
+        return Err("too many damaged");
     }
-    if damaged_runs.len() == 0 {
+    if damaged_runs.is_empty() {
         return Ok(1);
     }
     if states.num_operational + states.num_unknown < damaged_runs.len() - 1 {
-        return Err("not enough operational/unknowns".into());
+        return Err("not enough operational/unknowns");
     }
     if states.len() < sum_of_runs + damaged_runs.len() - 1 {
-        return Err("not enough states".into());
+        return Err("not enough states");
     }
     states = trim_operational(states);
     (states, damaged_runs) = trim_fixed_damaged(states, damaged_runs)?;
-    if states.len() == 0 {
-        return if damaged_runs.len() == 0 {
+    if states.is_empty() {
+        return if damaged_runs.is_empty() {
             Ok(1)
         } else {
-            Err("not enough states".into())
+            Err("not enough states")
         };
     }
     if let Some(run) = damaged_runs.first() {
@@ -280,7 +281,7 @@ fn arrangements<'a, 'b>(
             .iter()
             .any(|state| matches!(state, SpringState::Damaged))
         {
-            Err("inconsistent states".into())
+            Err("inconsistent states")
         } else {
             Ok(1)
         }
@@ -332,7 +333,7 @@ impl LineStreamHandler for Day12 {
         )?;
         if self.gold {
             let mut states_extended = (0..5)
-                .cartesian_product(states.iter().chain(vec![SpringState::Unknown].iter()))
+                .cartesian_product(states.iter().chain([SpringState::Unknown].iter()))
                 .map(|(_, state)| *state)
                 .collect::<Vec<_>>();
             states_extended.pop();
@@ -355,16 +356,15 @@ impl LineStreamHandler for Day12 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of arrangements: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            self.sum_of_arrangements
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.gold, "Sum of arrangements", self.sum_of_arrangements))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(12, "Hot Springs", Day12::new(gold)))
+    Ok(Day::new(Day12::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 12, "Hot Springs", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
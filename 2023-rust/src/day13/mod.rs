@@ -1,11 +1,13 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::LineStreamHandler,
 };
 
-fn find_symmetry(vec: &Vec<u64>, up_to_error_bits: u32) -> Option<usize> {
+fn find_symmetry(vec: &[u64], up_to_error_bits: u32) -> Option<usize> {
     'outer: for i in 1..vec.len() {
         let mut error_bits = 0;
         for j in 0..i.min(vec.len() - i) {
@@ -50,10 +52,10 @@ impl Day13 {
                 self.sum_of_notes += sym_column;
             }
             (None, None) => {
-                return Err("no symmetry found".into());
+                return Err(AocError::unsolvable("no symmetry found").into());
             }
             (Some(_), Some(_)) => {
-                return Err("multiple symmetries found".into());
+                return Err(AocError::unsolvable("multiple symmetries found").into());
             }
         }
         self.current_rows.clear();
@@ -81,13 +83,16 @@ impl LineStreamHandler for Day13 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.end_current()?;
-        println!("[{}] Sum of notes: {}", SILVER_ANSI, self.sum_of_notes);
-        Ok(())
+        Ok(Answers::new().with_part(self.gold, "Sum of notes", self.sum_of_notes))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(13, "Point of Incidence", Day13::new(gold)))
+    Ok(Day::new(Day13::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 13, "Point of Incidence", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
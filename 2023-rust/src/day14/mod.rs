@@ -1,9 +1,15 @@
-use std::{collections::HashMap, error::Error};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     point::{Direction2, Point2},
+    stepper::{self, Steppable},
     terrain::Terrain,
 };
 use nom::{branch::alt, character::complete::char, combinator::value, multi::many1};
@@ -15,16 +21,38 @@ enum Rock {
     Cube,
 }
 
+impl Display for Rock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_char(match self {
+            Rock::None => '.',
+            Rock::Rounded => 'O',
+            Rock::Cube => '#',
+        })
+    }
+}
+
+const CYCLE: [Direction2; 4] = [
+    Direction2::Up,
+    Direction2::Left,
+    Direction2::Down,
+    Direction2::Right,
+];
+
+#[derive(Clone)]
 struct Day14 {
     gold: bool,
+    stepper: bool,
     terrain: Terrain<Rock>,
+    tilts: usize,
 }
 
 impl Day14 {
-    fn new(gold: bool) -> Self {
+    fn new(gold: bool, stepper: bool) -> Self {
         Self {
             gold,
+            stepper,
             terrain: Terrain::new(),
+            tilts: 0,
         }
     }
     fn tilt(&mut self, towards: Direction2) {
@@ -53,10 +81,9 @@ impl Day14 {
         }
     }
     fn tilt_cycle(&mut self) {
-        self.tilt(Direction2::Up);
-        self.tilt(Direction2::Left);
-        self.tilt(Direction2::Down);
-        self.tilt(Direction2::Right);
+        for towards in CYCLE {
+            self.tilt(towards);
+        }
     }
     fn total_north_load(&self) -> usize {
         let mut load = 0usize;
@@ -78,16 +105,20 @@ impl LineStreamHandler for Day14 {
                 value(Rock::Rounded, char('O')),
                 value(Rock::Cube, char('#')),
             ))),
-        )?);
-        Ok(())
+        )?)
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        if self.stepper {
+            stepper::interact(self.as_ref().clone())?;
+        }
         if self.gold {
             let mut states: HashMap<Terrain<Rock>, usize> = HashMap::new();
             for i in 0usize..1_000_000_000 {
                 let first_seen = states.entry(self.terrain.clone()).or_insert(i);
-                if *first_seen != i && (1_000_000_000usize - *first_seen) % (i - *first_seen) == 0 {
+                if *first_seen != i
+                    && (1_000_000_000usize - *first_seen).is_multiple_of(i - *first_seen)
+                {
                     break;
                 }
                 self.tilt_cycle();
@@ -95,15 +126,54 @@ impl LineStreamHandler for Day14 {
         } else {
             self.tilt(Direction2::Up);
         }
-        println!(
-            "[{}] Total North Load: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            self.total_north_load()
-        );
-        Ok(())
+        Ok(Answers::new().with_part(self.gold, "Total North Load", self.total_north_load()))
+    }
+}
+
+/// Steps through single tilts, north only for silver and spin cycles for gold.
+impl Steppable for Day14 {
+    type Cell = Rock;
+
+    fn step(&mut self) -> bool {
+        if !self.gold && self.tilts > 0 {
+            return false;
+        }
+        self.tilt(CYCLE[self.tilts % CYCLE.len()]);
+        self.tilts += 1;
+        true
+    }
+
+    fn render(&self) -> Terrain<Rock> {
+        self.terrain.clone()
+    }
+
+    fn status(&self) -> String {
+        let tilted = match self.tilts.checked_sub(1) {
+            Some(last) => format!("tilted {:?}", CYCLE[last % CYCLE.len()]),
+            None => "not tilted".to_owned(),
+        };
+        if self.gold {
+            format!(
+                "cycle {}, {}, total north load {}",
+                self.tilts.div_ceil(CYCLE.len()),
+                tilted,
+                self.total_north_load()
+            )
+        } else {
+            format!("{}, total north load {}", tilted, self.total_north_load())
+        }
     }
 }
 
-pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(14, "Parabolic Reflector Dish", Day14::new(gold)))
+pub fn new(gold: bool, stepper: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day14::new(gold, stepper)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2023,
+    14,
+    "Parabolic Reflector Dish",
+    Parts::Selectable,
+    |args| new(args.flag("gold"), args.flag(Param::STEP.name)),
+)
+.with_params(&[Param::STEP]);
@@ -1,7 +1,9 @@
 use std::{error::Error, mem::take};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::LineStreamHandler,
 };
 
@@ -53,10 +55,12 @@ impl Instruction {
                     self.op = InstructionOp::Set((value * 10) + digit as usize);
                     Ok(())
                 } else {
-                    Err("unexpected non-digit after '='".into())
+                    Err(AocError::invalid_input("unexpected non-digit after '='").into())
                 }
             }
-            InstructionOp::Remove => Err("unexpected character after '-'".into()),
+            InstructionOp::Remove => {
+                Err(AocError::invalid_input("unexpected character after '-'").into())
+            }
         }
     }
 }
@@ -81,7 +85,9 @@ impl Day15 {
         let bucket = &mut self.buckets[instruction.bucket as usize];
         let entry_pos = bucket.iter().position(|entry| entry.0 == instruction.key);
         match instruction.op {
-            InstructionOp::Incomplete => Err("incomplete instruction".into()),
+            InstructionOp::Incomplete => {
+                Err(AocError::invalid_input("incomplete instruction").into())
+            }
             InstructionOp::Set(value) => {
                 if let Some(pos) = entry_pos {
                     bucket[pos].1 = value;
@@ -121,23 +127,18 @@ impl LineStreamHandler for Day15 {
             }
         }
         self.end_instruction()?;
-        println!(
-            "[{}] Sum of instruction HASHes: {}",
-            SILVER_ANSI, self.total_instruction_hash
-        );
-        println!(
-            "[{}] Sum of lens powers:        {}",
-            GOLD_ANSI,
-            self.total_power()
-        );
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Sum of instruction HASHes", self.total_instruction_hash)
+            .with_gold("Sum of lens powers", self.total_power()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(15, "Lens Library", Day15::new()))
+    Ok(Day::new(Day15::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 15, "Lens Library", Parts::Both, |_| new());
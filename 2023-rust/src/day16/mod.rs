@@ -1,7 +1,9 @@
 use std::{error::Error, mem::take};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::LineStreamHandler,
     point::{Direction2, Point2},
     terrain::Terrain,
@@ -58,7 +60,7 @@ impl TryFrom<char> for Device {
             '|' => Ok(Self::VerticalSplitter),
             '/' => Ok(Self::NeswMirror),
             '\\' => Ok(Self::NwseMirror),
-            _ => Err("unrecognized character".into()),
+            _ => Err(AocError::invalid_input("unrecognized character").into()),
         }
     }
 }
@@ -109,7 +111,7 @@ impl Tile {
 fn energize(terrain: &mut Terrain<Tile>, from: Point2<usize>, towards: Direction2) {
     let size = terrain.size();
     let mut next_beams: Vec<(Point2<usize>, Direction2)> = vec![(from, towards)];
-    while next_beams.len() > 0 {
+    while !next_beams.is_empty() {
         for (pt, dir) in take(&mut next_beams) {
             let tile = &mut terrain[pt];
             tile.energized = true;
@@ -137,13 +139,7 @@ fn energize(terrain: &mut Terrain<Tile>, from: Point2<usize>, towards: Direction
 }
 
 fn count_energized(terrain: &Terrain<Tile>) -> usize {
-    let mut num_energized = 0;
-    for pt in terrain.points() {
-        if terrain[pt].energized {
-            num_energized += 1;
-        }
-    }
-    num_energized
+    terrain.iter().filter(|tile| tile.energized).count()
 }
 
 struct Day16 {
@@ -166,11 +162,11 @@ impl LineStreamHandler for Day16 {
         for ch in line.chars() {
             row.push(Tile::new(ch.try_into()?));
         }
-        self.terrain.push_row(row);
+        self.terrain.push_row(row)?;
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if self.gold {
             let mut max_energized = 0;
             for y in 0..self.terrain.height() {
@@ -197,22 +193,23 @@ impl LineStreamHandler for Day16 {
                 );
                 max_energized = max_energized.max(count_energized(&u_terrain));
             }
-            println!(
-                "[{}] Optimal number of energized tiles: {}",
-                GOLD_ANSI, max_energized
-            );
+            Ok(Answers::new().with_gold("Optimal number of energized tiles", max_energized))
         } else {
             energize(&mut self.terrain, Point2(0, 0), Direction2::Right);
-            println!(
-                "[{}] Number of energized tiles: {}",
-                SILVER_ANSI,
-                count_energized(&self.terrain)
-            );
+            Ok(Answers::new()
+                .with_silver("Number of energized tiles", count_energized(&self.terrain)))
         }
-        Ok(())
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(16, "The Floor Will Be Lava", Day16::new(gold)))
+    Ok(Day::new(Day16::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2023,
+    16,
+    "The Floor Will Be Lava",
+    Parts::Selectable,
+    |args| new(args.flag("gold")),
+);
@@ -1,5 +1,7 @@
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
     point::{Direction2, Point2},
 };
@@ -23,9 +25,9 @@ fn area(perimeter: &Vec<(Direction2, usize)>) -> Result<usize, Box<dyn Error>> {
         length_of_perimeter += distance;
     }
     if head != Point2(0, 0) {
-        return Err("unclosed loop".into());
+        return Err(AocError::invalid_input("unclosed loop").into());
     }
-    Ok(sum_of_determinants.abs() as usize / 2 + length_of_perimeter / 2 + 1)
+    Ok(sum_of_determinants.unsigned_abs() / 2 + length_of_perimeter / 2 + 1)
 }
 
 struct Day18 {
@@ -76,16 +78,15 @@ impl LineStreamHandler for Day18 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Trench area: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            area(&self.perimeter)?
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.gold, "Trench area", area(&self.perimeter)?))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(18, "Lavaduct Lagoon", Day18::new(gold)))
+    Ok(Day::new(Day18::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 18, "Lavaduct Lagoon", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -6,8 +6,10 @@ use std::{
 };
 
 use aoc_common_rs::{
+    answers::Answers,
     cc::ThreeCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandlerOnce},
 };
 use nom::{
@@ -35,7 +37,7 @@ impl TryFrom<char> for PartRating {
             'm' => Ok(Self::M),
             'a' => Ok(Self::A),
             's' => Ok(Self::S),
-            _ => Err("invalid part rating".into()),
+            _ => Err(AocError::invalid_input("invalid part rating").into()),
         }
     }
 }
@@ -184,7 +186,7 @@ fn parse_workflow_id(value: &str) -> Result<ThreeCC, Box<dyn Error>> {
     if value.len() <= 3 {
         Ok(ThreeCC::from_lax(value))
     } else {
-        Err("workflow ID cannot be longer than 3 chars".into())
+        Err(AocError::invalid_input("workflow ID cannot be longer than 3 chars").into())
     }
 }
 
@@ -322,7 +324,7 @@ impl LineStreamHandlerOnce for Day19Workflows {
                             ),
                             opt(char(',')),
                         ),
-                        || Workflow::new(),
+                        Workflow::new,
                         |mut workflow, (condition, action)| {
                             if let Some((rating, ordering, threshold)) = condition {
                                 workflow.rules.push((rating, ordering, threshold, action));
@@ -341,7 +343,7 @@ impl LineStreamHandlerOnce for Day19Workflows {
         Ok(self)
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         unreachable!()
     }
 }
@@ -366,7 +368,7 @@ impl LineStreamHandlerOnce for Day19Parts {
                         ),
                         opt(char(',')),
                     ),
-                    || Part::new(),
+                    Part::new,
                     |part, (rating, value)| part.with_rating(rating, value),
                 ),
                 char('}'),
@@ -383,12 +385,8 @@ impl LineStreamHandlerOnce for Day19Parts {
         Ok(self)
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Total accepted part rating: {}",
-            SILVER_ANSI, self.0.total_rating
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_silver("Total accepted part rating", self.0.total_rating))
     }
 }
 
@@ -402,25 +400,21 @@ impl LineStreamHandlerOnce for Day19Satisfiability {
         Ok(self)
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut accepted_count = 0;
         let mut next = VecDeque::new();
         next.push_back((PartClass::new(1..=4000), ThreeCC::new('i', 'n', '\0')));
         while let Some((part_class, workflow_id)) = next.pop_front() {
             accepted_count += self.0.workflows[&workflow_id].apply_class(part_class, &mut next);
         }
-        println!(
-            "[{}] Distinct accepted parts: {}",
-            GOLD_ANSI, accepted_count
-        );
-        Ok(())
+        Ok(Answers::new().with_gold("Distinct accepted parts", accepted_count))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new_once(
-        19,
-        "Aplenty",
-        Day19Workflows(Day19::new(gold)),
-    ))
+    Ok(Day::new_once(Day19Workflows(Day19::new(gold))))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 19, "Aplenty", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
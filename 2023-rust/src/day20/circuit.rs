@@ -4,7 +4,9 @@ use std::{
     ops::Not,
 };
 
-use aoc_common_rs::{cc::TwoCC, mapping::Mapping, math::lcm, ord::insert_sorted};
+use log::debug;
+
+use aoc_common_rs::{cc::TwoCC, error::AocError, mapping::Mapping, math::lcm, ord::insert_sorted};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u8)]
@@ -91,9 +93,11 @@ impl Module {
     }
 }
 
+type Node = (Module, Vec<(usize, usize)>, Vec<usize>);
+
 #[derive(Clone, Debug)]
 pub struct Circuit {
-    modules: Vec<(Module, Vec<(usize, usize)>, Vec<usize>)>,
+    modules: Vec<Node>,
 }
 
 impl Circuit {
@@ -220,10 +224,10 @@ impl CircuitBuilder {
             }
         }
         for sink in sinks.into_iter() {
-            eprintln!("[-] Adding sink {:?}", sink);
+            debug!("Adding sink {:?}", sink);
             self.modules.insert(sink, (Module::Sink, vec![]));
         }
-        let mapping = self.modules.keys().map(|key| *key).collect::<Mapping<_>>();
+        let mapping = self.modules.keys().copied().collect::<Mapping<_>>();
         let mut inputs = vec![Vec::new(); mapping.len()];
         for (input, (_, outputs)) in self.modules.iter() {
             for output in outputs.iter() {
@@ -236,12 +240,9 @@ impl CircuitBuilder {
             .zip(mapping.iter())
             .map(|(module_inputs, (module_id, module_index))| {
                 let (mut module, outputs) = self.modules.remove(module_id).unwrap();
-                match &mut module {
-                    Module::Conjunction(inputs, num_low) => {
-                        *inputs = vec![Pulse::Low; module_inputs.len()];
-                        *num_low = module_inputs.len();
-                    }
-                    _ => {}
+                if let Module::Conjunction(inputs, num_low) = &mut module {
+                    *inputs = vec![Pulse::Low; module_inputs.len()];
+                    *num_low = module_inputs.len();
                 }
                 (
                     module,
@@ -262,10 +263,10 @@ impl CircuitBuilder {
                 )
             })
             .collect::<Vec<_>>();
-        assert!(self.modules.len() == 0);
+        assert!(self.modules.is_empty());
         for (module, _, inputs) in modules.iter() {
             if matches!(module, Module::Counter(_, _)) && inputs.is_empty() {
-                return Err("unreachable counter module".into());
+                return Err(AocError::unsolvable("unreachable counter module").into());
             }
         }
         Ok((Circuit { modules }, mapping))
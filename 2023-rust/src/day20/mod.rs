@@ -1,8 +1,9 @@
 use std::error::Error;
 
 use aoc_common_rs::{
+    answers::Answers,
     cc::TwoCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    day::{Day, Param, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 use nom::{
@@ -62,20 +63,20 @@ impl LineStreamHandler for Day20 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if self.gold {
             self.builder.add_module(RX, Module::new_counter(), vec![]);
         }
         let (mut circuit, mapping) = self.builder.build()?;
         if self.export {
             circuit.export(&mapping);
-            return Ok(());
+            return Ok(Answers::new());
         }
         let broadcaster_index = mapping.index(&Default::default()).unwrap();
         if self.gold {
             let rx_index = mapping.index(&RX).unwrap();
             let triggers = circuit.triggers_before_low(broadcaster_index, rx_index);
-            println!("[{}] Triggers before rx low: {}", GOLD_ANSI, triggers);
+            Ok(Answers::new().with_gold("Triggers before rx low", triggers))
         } else {
             let mut total_low = 0;
             let mut total_high = 0;
@@ -84,18 +85,19 @@ impl LineStreamHandler for Day20 {
                 total_low += low;
                 total_high += high;
             }
-            println!("[-] Low pulses:        {}", total_low);
-            println!("[-] High pulses:       {}", total_high);
-            println!(
-                "[{}] Product of pulses: {}",
-                SILVER_ANSI,
-                total_low * total_high
-            );
+            Ok(Answers::new()
+                .with_silver("Product of pulses", total_low * total_high)
+                .with_extra("Low pulses", total_low)
+                .with_extra("High pulses", total_high))
         }
-        Ok(())
     }
 }
 
 pub fn new(gold: bool, export: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(20, "Pulse Propagation", Day20::new(gold, export)).with_display_banner(!export))
+    Ok(Day::new(Day20::new(gold, export)).with_display_banner(!export))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2023, 20, "Pulse Propagation", Parts::Selectable, |args| {
+    new(args.flag("gold"), args.flag("export"))
+})
+.with_params(&[Param::flag("export", 'x')]);
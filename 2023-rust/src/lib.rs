@@ -0,0 +1,53 @@
+use aoc_common_rs::day::Puzzle;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+
+/// The solved days, in order. `Puzzle::solve` runs one on an input in memory.
+pub const PUZZLES: &[Puzzle] = &[
+    day01::PUZZLE,
+    day02::PUZZLE,
+    day03::PUZZLE,
+    day04::PUZZLE,
+    day05::PUZZLE,
+    day06::PUZZLE,
+    day07::PUZZLE,
+    day08::PUZZLE,
+    day09::PUZZLE,
+    day10::PUZZLE,
+    day11::PUZZLE,
+    day12::PUZZLE,
+    day13::PUZZLE,
+    day14::PUZZLE,
+    day15::PUZZLE,
+    day16::PUZZLE,
+    day17::PUZZLE,
+    day18::PUZZLE,
+    day19::PUZZLE,
+    day20::PUZZLE,
+    day21::PUZZLE,
+];
+
+#[cfg(test)]
+mod sample_tests {
+    include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+}
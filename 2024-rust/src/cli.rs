@@ -1,85 +0,0 @@
-use clap::{Parser, Subcommand};
-
-#[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
-pub struct Cli {
-    #[arg(short, long)]
-    pub timed: bool,
-    #[command(subcommand)]
-    pub command: Commands,
-}
-
-#[derive(Debug, Subcommand)]
-pub enum Commands {
-    Day01 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day02 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day03 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day04,
-    Day05,
-    Day06,
-    Day07 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day08 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day09 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day10,
-    Day11,
-    Day12,
-    Day13 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day14 {
-        #[arg(short, long)]
-        frame: Option<i64>,
-    },
-    Day15 {
-        #[arg(short, long)]
-        gold: bool,
-        #[arg(short, long)]
-        verbose: bool,
-    },
-    Day16 {
-        #[arg(short, long)]
-        verbose: bool,
-    },
-    Day17 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day18 {
-        #[arg(short, long)]
-        sample: bool,
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day19,
-    Day20 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day21 {
-        #[arg(short, long)]
-        gold: bool,
-    },
-    Day22,
-    Day23,
-    Day24,
-    Day25,
-}
@@ -1,5 +1,6 @@
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     unwrap_either,
 };
@@ -38,16 +39,12 @@ impl LineStreamHandler for Day1Silver {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut distance = 0u32;
         for (left, right) in self.left.iter().zip(self.right.iter()) {
             distance += left.abs_diff(*right);
         }
-        println!(
-            "[{}] Total distance between lists: {}",
-            SILVER_ANSI, distance
-        );
-        Ok(())
+        Ok(Answers::new().with_silver("Total distance between lists", distance))
     }
 }
 
@@ -83,22 +80,25 @@ impl LineStreamHandler for Day1Gold {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut similarity = 0usize;
         for (id, left_count) in self.left.iter() {
             if let Some(right_count) = self.right.get(id) {
                 similarity += (*id as usize) * left_count * right_count;
             }
         }
-        println!("[{}] Similarity score: {}", GOLD_ANSI, similarity);
-        Ok(())
+        Ok(Answers::new().with_gold("Similarity score", similarity))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
     Ok(if gold {
-        Day::new(1, "Historian Hysteria", Day1Gold::new())
+        Day::new(Day1Gold::new())
     } else {
-        Day::new(1, "Historian Hysteria", Day1Silver::new())
+        Day::new(Day1Silver::new())
     })
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 1, "Historian Hysteria", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -1,5 +1,6 @@
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 use itertools::chain;
@@ -64,16 +65,15 @@ impl LineStreamHandler for Day2 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Safe report count: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            self.safe_reports
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.gold, "Safe report count", self.safe_reports))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(2, "Red-Nosed Reports", Day2::new(gold)))
+    Ok(Day::new(Day2::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 2, "Red-Nosed Reports", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -1,7 +1,8 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 use nom::{
@@ -101,20 +102,19 @@ impl LineStreamHandler for Day3 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of products: {}",
-            if self.accumulator.can_disable {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.accumulator.sum
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(
+            self.accumulator.can_disable,
+            "Sum of products",
+            self.accumulator.sum,
+        ))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(3, "Mull It Over", Day3::new(gold)))
+    Ok(Day::new(Day3::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 3, "Mull It Over", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
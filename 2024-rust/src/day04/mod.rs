@@ -1,14 +1,10 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::{parse_full_string, LineStreamHandler},
-};
-use nom::{
-    branch::alt,
-    character::complete::{anychar, char},
-    combinator::map,
-    multi::fold_many1,
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    point::{Direction8, Point2},
+    terrain::Terrain,
 };
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -20,173 +16,68 @@ enum Letter {
     Invalid,
 }
 
-fn count_horizontals(row: &Vec<Letter>) -> usize {
-    use Letter::*;
-
-    let mut occurrences = 0usize;
-    for (letter, i) in row.iter().zip(0..(row.len() - 3)) {
-        match *letter {
-            X => {
-                if row[i + 1] == M && row[i + 2] == A && row[i + 3] == S {
-                    occurrences += 1;
-                }
-            }
-            S => {
-                if row[i + 1] == A && row[i + 2] == M && row[i + 3] == X {
-                    occurrences += 1;
-                }
-            }
-            _ => {}
+impl From<char> for Letter {
+    fn from(value: char) -> Self {
+        match value {
+            'X' => Self::X,
+            'M' => Self::M,
+            'A' => Self::A,
+            'S' => Self::S,
+            _ => Self::Invalid,
         }
     }
-    occurrences
 }
 
-fn count_non_horizontals(window: &[Option<Vec<Letter>>], shift: usize) -> usize {
-    use Letter::*;
-
-    let mut occurrences = 0usize;
-    let row0 = window[shift].as_ref().unwrap();
-    let row1 = window[(shift + 1) % window.len()].as_ref().unwrap();
-    let row2 = window[(shift + 2) % window.len()].as_ref().unwrap();
-    let row3 = window[(shift + 3) % window.len()].as_ref().unwrap();
+const XMAS: [Letter; 4] = [Letter::X, Letter::M, Letter::A, Letter::S];
 
-    for (letter, i) in row0.iter().zip(0usize..) {
-        // Verticals
-        match *letter {
-            X => {
-                if row1[i] == M && row2[i] == A && row3[i] == S {
-                    occurrences += 1;
-                }
-            }
-            S => {
-                if row1[i] == A && row2[i] == M && row3[i] == X {
-                    occurrences += 1;
-                }
-            }
-            _ => {}
+fn spells_xmas(grid: &Terrain<Letter>, from: Point2<usize>, towards: Direction8) -> bool {
+    let mut pt = from;
+    for (i, letter) in XMAS.iter().enumerate() {
+        if i > 0 {
+            pt = match grid.neighbor8(pt, towards) {
+                Some(next) => next,
+                None => return false,
+            };
         }
-    }
-
-    for (letter, i) in row0.iter().zip(0..(row0.len() - 3)) {
-        // Rightward diagonals
-        match *letter {
-            X => {
-                if row1[i + 1] == M && row2[i + 2] == A && row3[i + 3] == S {
-                    occurrences += 1;
-                }
-            }
-            S => {
-                if row1[i + 1] == A && row2[i + 2] == M && row3[i + 3] == X {
-                    occurrences += 1;
-                }
-            }
-            _ => {}
+        if grid[pt] != *letter {
+            return false;
         }
     }
-
-    for (letter, i) in row0[3..].iter().zip(3usize..) {
-        // Leftward diagonals
-        match *letter {
-            X => {
-                if row1[i - 1] == M && row2[i - 2] == A && row3[i - 3] == S {
-                    occurrences += 1;
-                }
-            }
-            S => {
-                if row1[i - 1] == A && row2[i - 2] == M && row3[i - 3] == X {
-                    occurrences += 1;
-                }
-            }
-            _ => {}
-        }
-    }
-
-    occurrences
+    true
 }
 
-fn count_crosses(window: &[Option<Vec<Letter>>], shift: usize) -> usize {
+fn is_cross(grid: &Terrain<Letter>, center: Point2<usize>) -> bool {
     use Letter::*;
 
-    let mut crosses = 0usize;
-    let row0 = window[shift].as_ref().unwrap();
-    let row1 = window[(shift + 1) % window.len()].as_ref().unwrap();
-    let row2 = window[(shift + 2) % window.len()].as_ref().unwrap();
-
-    for (letter, i) in row0.iter().zip(0..(row0.len() - 2)) {
-        if row1[i + 1] == A
-            && (*letter == M && row2[i + 2] == S || *letter == S && row2[i + 2] == M)
-            && (row0[i + 2] == M && row2[i] == S || row0[i + 2] == S && row2[i] == M)
-        {
-            crosses += 1;
-        }
-    }
-
-    crosses
-}
-
-#[derive(Debug)]
-struct Day4 {
-    window: [Option<Vec<Letter>>; 4],
-    next_index: usize,
-    width: usize,
-    occurrences: usize,
-    crosses: usize,
-}
-
-impl Day4 {
-    fn new() -> Self {
-        Self {
-            window: [None, None, None, None],
-            next_index: 0,
-            width: 0,
-            occurrences: 0,
-            crosses: 0,
-        }
-    }
-}
-
-impl LineStreamHandler for Day4 {
-    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
-        let row = parse_full_string(
-            line,
-            fold_many1(
-                alt((
-                    map(char('X'), |_| Letter::X),
-                    map(char('M'), |_| Letter::M),
-                    map(char('A'), |_| Letter::A),
-                    map(char('S'), |_| Letter::S),
-                    map(anychar, |_| Letter::Invalid),
-                )),
-                || Vec::with_capacity(self.width),
-                |mut acc, letter| {
-                    acc.push(letter);
-                    acc
+    grid[center] == A
+        && [Direction8::UpLeft, Direction8::UpRight]
+            .into_iter()
+            .all(
+                |dir| match (grid.neighbor8(center, dir), grid.neighbor8(center, -dir)) {
+                    (Some(one), Some(other)) => {
+                        matches!((grid[one], grid[other]), (M, S) | (S, M))
+                    }
+                    _ => false,
                 },
-            ),
-        )?;
-        self.width = self.width.max(row.len());
-        self.occurrences += count_horizontals(&row);
-        self.window[self.next_index] = Some(row);
-        self.next_index = (self.next_index + 1) % self.window.len();
-        if self.window[self.next_index].is_some() {
-            self.occurrences += count_non_horizontals(&self.window, self.next_index);
-            self.crosses += count_crosses(&self.window, self.next_index);
-        }
-        Ok(())
-    }
+            )
+}
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        self.next_index = (self.next_index + 1) % self.window.len();
-        if self.window[self.next_index].is_some() {
-            self.crosses += count_crosses(&self.window, self.next_index);
-        }
-        println!("[{}] Number of XMAS:  {}", SILVER_ANSI, self.occurrences);
-        println!("[{}] Number of X-MAS: {}", GOLD_ANSI, self.crosses);
-        Ok(())
-    }
+fn finish(grid: Terrain<Letter>) -> Result<Answers, Box<dyn Error>> {
+    let occurrences = grid
+        .points()
+        .flat_map(|pt| Direction8::all().map(move |dir| (pt, dir)))
+        .filter(|&(pt, dir)| spells_xmas(&grid, pt, dir))
+        .count();
+    let crosses = grid.points().filter(|&pt| is_cross(&grid, pt)).count();
+    Ok(Answers::new()
+        .with_silver("Number of XMAS", occurrences)
+        .with_gold("Number of X-MAS", crosses))
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(4, "Ceres Search", Day4::new()))
+    Ok(Day::new(
+        Terrain::builder().into_handler(|grid, _| finish(grid)),
+    ))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 4, "Ceres Search", Parts::Both, |_| new());
@@ -1,9 +1,10 @@
 use std::error::Error;
 
 use aoc_common_rs::{
+    answers::Answers,
     bit_matrix::BitMatrix128,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::{parse_full_string, LineStreamHandlerOnce},
+    day::{Day, Parts, Puzzle},
+    line_stream::{parse_full_string, SectionedHandler},
 };
 use nom::{
     character::complete::{char, u32, u8},
@@ -51,54 +52,35 @@ impl Day5 {
             self.sum_of_middles += middle;
         }
     }
-}
-
-struct Day5Orderings(Day5);
-
-impl LineStreamHandlerOnce for Day5Orderings {
-    fn update(
-        mut self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
-        if line.is_empty() {
-            return Ok(Box::new(Day5Updates(self.0)));
-        }
 
+    fn add_ordering(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         let (before, after) = parse_full_string(line, separated_pair(u8, char('|'), u8))?;
-        self.0.orderings.set(before as usize, after as usize, true);
-        Ok(self)
-    }
-
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        unreachable!()
+        self.orderings.set(before as usize, after as usize, true);
+        Ok(())
     }
-}
-
-struct Day5Updates(Day5);
 
-impl LineStreamHandlerOnce for Day5Updates {
-    fn update(
-        mut self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
+    fn add_update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         let pages = parse_full_string(line, separated_list1(char(','), u32))?;
-        self.0.process_update(pages);
-        Ok(self)
+        self.process_update(pages);
+        Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of middle pages:           {}",
-            SILVER_ANSI, self.0.sum_of_middles
-        );
-        println!(
-            "[{}] Sum of reordered middle pages: {}",
-            GOLD_ANSI, self.0.sum_of_reordered_middles
-        );
-        Ok(())
+    fn finish(self) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Sum of middle pages", self.sum_of_middles)
+            .with_gold(
+                "Sum of reordered middle pages",
+                self.sum_of_reordered_middles,
+            ))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new_once(5, "Print Queue", Day5Orderings(Day5::new())))
+    Ok(Day::new(
+        SectionedHandler::new(Day5::new(), Day5::finish)
+            .with_section(Day5::add_ordering)
+            .with_section(Day5::add_update),
+    ))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 5, "Print Queue", Parts::Both, |_| new());
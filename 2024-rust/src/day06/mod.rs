@@ -1,7 +1,8 @@
 use std::{collections::HashSet, error::Error};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
     point::{Direction2, Point2},
     terrain::Terrain,
@@ -69,28 +70,23 @@ impl LineStreamHandler for Day6 {
         for (ch, i) in line.chars().zip(0usize..) {
             row.push(ch == '#');
             if ch == '^' {
-                self.start = Some(Point2(i, self.terrain.len()));
+                self.start = Some(Point2(i, self.terrain.height()));
             }
         }
-        self.terrain.push_row(row);
+        self.terrain.push_row(row)?;
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Visited positions:     {}",
-            SILVER_ANSI,
-            self.walk().0.len()
-        );
-        println!(
-            "[{}] Possible obstructions: {}",
-            GOLD_ANSI,
-            self.count_possible_obstructions()
-        );
-        Ok(())
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let visited = self.walk().0.len();
+        Ok(Answers::new()
+            .with_silver("Visited positions", visited)
+            .with_gold("Possible obstructions", self.count_possible_obstructions()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(6, "Guard Gallivant", Day6::new()))
+    Ok(Day::new(Day6::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 6, "Guard Gallivant", Parts::Both, |_| new());
@@ -1,7 +1,8 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 use nom::{
@@ -59,20 +60,15 @@ impl LineStreamHandler for Day7 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Total calibration result: {}",
-            if self.allow_concat {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.sum
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.allow_concat, "Total calibration result", self.sum))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(7, "Bridge Repair", Day7::new(gold)))
+    Ok(Day::new(Day7::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 7, "Bridge Repair", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -4,7 +4,8 @@ use std::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
     point::Point2,
 };
@@ -84,7 +85,7 @@ impl LineStreamHandler for Day8 {
             if ch != '.' {
                 self.antennas
                     .entry(ch)
-                    .or_insert_with(|| HashSet::new())
+                    .or_default()
                     .insert(Point2(i, self.height));
             }
         }
@@ -93,24 +94,24 @@ impl LineStreamHandler for Day8 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        if self.with_harmonics {
-            println!(
-                "[{}] Unique antinode locations: {}",
-                GOLD_ANSI,
-                self.count_antinodes_with_harmonics()
-            );
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let count = if self.with_harmonics {
+            self.count_antinodes_with_harmonics()
         } else {
-            println!(
-                "[{}] Unique antinode locations: {}",
-                SILVER_ANSI,
-                self.count_antinodes()
-            );
-        }
-        Ok(())
+            self.count_antinodes()
+        };
+        Ok(Answers::new().with_part(self.with_harmonics, "Unique antinode locations", count))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(8, "Resonant Collinearity", Day8::new(gold)))
+    Ok(Day::new(Day8::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2024,
+    8,
+    "Resonant Collinearity",
+    Parts::Selectable,
+    |args| new(args.flag("gold")),
+);
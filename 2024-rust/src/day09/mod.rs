@@ -1,7 +1,8 @@
 use std::{cmp::Ordering, error::Error, num::NonZeroUsize};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
 };
 
@@ -70,7 +71,7 @@ impl Day9 {
             return None;
         }
 
-        (&self.disk[offset..])
+        self.disk[offset..]
             .iter()
             .zip(offset..)
             .find(|(el, _)| el.contents.is_none() && el.length >= min_length)
@@ -197,26 +198,21 @@ impl LineStreamHandler for Day9 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if self.fragmenting {
             self.compact_fragmenting();
         } else {
             self.compact_nonfragmenting();
         }
         self.optimize();
-        println!(
-            "[{}] Filesystem checksum: {}",
-            if self.fragmenting {
-                SILVER_ANSI
-            } else {
-                GOLD_ANSI
-            },
-            self.checksum()
-        );
-        Ok(())
+        Ok(Answers::new().with_part(!self.fragmenting, "Filesystem checksum", self.checksum()))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(9, "Disk Fragmenter", Day9::new(!gold)))
+    Ok(Day::new(Day9::new(!gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 9, "Disk Fragmenter", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
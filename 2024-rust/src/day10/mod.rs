@@ -4,7 +4,8 @@ use std::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
     point::Point2,
     terrain::Terrain,
@@ -39,16 +40,16 @@ impl LineStreamHandler for Day10 {
                 self.trailheads.insert(Point2(i, self.height_map.height()));
             }
         }
-        self.height_map.push_row(row);
+        self.height_map.push_row(row)?;
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut positions = HashMap::new();
         for trailhead in self.trailheads {
             positions
                 .entry(trailhead)
-                .or_insert_with(|| HashMap::new())
+                .or_insert_with(HashMap::new)
                 .insert(trailhead, 1usize);
         }
         for height in 1..=9 {
@@ -59,9 +60,8 @@ impl LineStreamHandler for Day10 {
             for (position, trailheads) in positions {
                 for (_, neighbor) in self.height_map.neighbors(position) {
                     if self.height_map[neighbor] == height {
-                        let new_trailheads = new_positions
-                            .entry(neighbor)
-                            .or_insert_with(|| HashMap::new());
+                        let new_trailheads =
+                            new_positions.entry(neighbor).or_insert_with(HashMap::new);
                         for (trailhead, paths) in &trailheads {
                             *new_trailheads.entry(*trailhead).or_insert(0usize) += paths;
                         }
@@ -78,12 +78,14 @@ impl LineStreamHandler for Day10 {
                 rating += *paths;
             }
         }
-        println!("[{}] Sum of trailhead scores:  {}", SILVER_ANSI, score);
-        println!("[{}] Sum of trailhead ratings: {}", GOLD_ANSI, rating);
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Sum of trailhead scores", score)
+            .with_gold("Sum of trailhead ratings", rating))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(10, "Hoof It", Day10::new()))
+    Ok(Day::new(Day10::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 10, "Hoof It", Parts::Both, |_| new());
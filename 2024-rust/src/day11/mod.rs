@@ -1,7 +1,8 @@
-use std::{collections::HashMap, error::Error, mem::replace};
+use std::{collections::HashMap, error::Error, mem::take};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
 };
 use nom::{
@@ -22,7 +23,7 @@ impl Day11 {
     }
 
     fn step(&mut self) {
-        for (stone, freq) in replace(&mut self.frequencies, HashMap::new()) {
+        for (stone, freq) in take(&mut self.frequencies) {
             let (new_stone1, maybe_new_stone2) = step(stone);
             *self.frequencies.entry(new_stone1).or_insert(0) += freq;
             if let Some(new_stone2) = maybe_new_stone2 {
@@ -61,27 +62,20 @@ impl LineStreamHandler for Day11 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         for _ in 0usize..25 {
             self.step();
         }
-        println!(
-            "[{}] Total stone count after 25 blinks: {}",
-            SILVER_ANSI,
-            self.count()
-        );
+        let answers = Answers::new().with_silver("Total stone count after 25 blinks", self.count());
         for _ in 0usize..50 {
             self.step();
         }
-        println!(
-            "[{}] Total stone count after 75 blinks: {}",
-            GOLD_ANSI,
-            self.count()
-        );
-        Ok(())
+        Ok(answers.with_gold("Total stone count after 75 blinks", self.count()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(11, "Plutonian Pebbles", Day11::new()))
+    Ok(Day::new(Day11::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 11, "Plutonian Pebbles", Parts::Both, |_| new());
@@ -1,7 +1,8 @@
-use std::{cell::RefCell, error::Error, usize};
+use std::{cell::RefCell, error::Error};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
     point::Direction2,
     terrain::Terrain,
@@ -146,26 +147,20 @@ impl LineStreamHandler for Day12 {
         for ch in line.chars() {
             row.push(Plot::new(ch));
         }
-        self.garden.push_row(row);
+        self.garden.push_row(row)?;
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.calculate_regions();
-        println!(
-            "[{}] Total fence cost:      {}",
-            SILVER_ANSI,
-            self.fence_cost()
-        );
-        println!(
-            "[{}] Discounted fence cost: {}",
-            GOLD_ANSI,
-            self.discounted_fence_cost()
-        );
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Total fence cost", self.fence_cost())
+            .with_gold("Discounted fence cost", self.discounted_fence_cost()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(12, "Garden Groups", Day12::new()))
+    Ok(Day::new(Day12::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 12, "Garden Groups", Parts::Both, |_| new());
@@ -1,7 +1,8 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
     math::diophantine::LinearBivariateDiophantineEquation,
     mem::take_all3,
@@ -116,21 +117,16 @@ impl LineStreamHandler for Day13 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.process();
-        println!(
-            "[{}] Fewest tokens: {}",
-            if self.prize_offset != 0 {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.total_tokens
-        );
-        Ok(())
+        Ok(Answers::new().with_part(self.prize_offset != 0, "Fewest tokens", self.total_tokens))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(13, "Claw Contraption", Day13::new(gold)))
+    Ok(Day::new(Day13::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 13, "Claw Contraption", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
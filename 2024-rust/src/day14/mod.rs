@@ -2,16 +2,20 @@ use std::{
     cmp::Ordering,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult, Write},
+    path::{Path, PathBuf},
 };
 
 use aoc_common_rs::{
-    day::{Day, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
+    frames::{FrameRecorder, Rgb},
     line_stream::{parse_full_string, LineStreamHandler},
     math::lcm,
     point::Point2,
     terrain::Terrain,
 };
 
+use log::{debug, info};
 use nom::{
     bytes::complete::tag,
     character::complete::{char, i64, u64},
@@ -48,9 +52,9 @@ impl Robot {
 #[derive(Clone, Copy, Default)]
 struct HistogramCell(usize);
 
-impl Display for HistogramCell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_char(match self.0 {
+impl HistogramCell {
+    fn glyph(&self) -> char {
+        match self.0 {
             0 => '.',
             1 => '1',
             2 => '2',
@@ -62,7 +66,20 @@ impl Display for HistogramCell {
             8 => '8',
             9 => '9',
             _ => '+',
-        })
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self.0 {
+            0 => Rgb(16, 16, 16),
+            robots => Rgb(0, 96, 0).mix(Rgb(160, 255, 160), (robots - 1) as f64 / 3.0),
+        }
+    }
+}
+
+impl Display for HistogramCell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_char(self.glyph())
     }
 }
 
@@ -70,13 +87,15 @@ impl Display for HistogramCell {
 struct Day14 {
     robots: Vec<Robot>,
     display_frame: Option<i64>,
+    record: Option<PathBuf>,
 }
 
 impl Day14 {
-    fn new(display_frame: Option<i64>) -> Self {
+    fn new(display_frame: Option<i64>, record: Option<PathBuf>) -> Self {
         Self {
             robots: Vec::new(),
             display_frame,
+            record,
         }
     }
 
@@ -104,6 +123,20 @@ impl Day14 {
         }
         terrain
     }
+
+    /// Records every second up to the displayed frame, or up to the 100th.
+    fn record(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut recorder = FrameRecorder::new(HistogramCell::color)
+            .with_glyph(HistogramCell::glyph)
+            .with_scale(2);
+        let mut record_clone = self.clone();
+        recorder.record(&record_clone.compute_histogram());
+        for _ in 0..self.display_frame.unwrap_or(100) {
+            record_clone.step_all(1);
+            recorder.record(&record_clone.compute_histogram());
+        }
+        recorder.save(path)
+    }
 }
 
 impl LineStreamHandler for Day14 {
@@ -125,17 +158,20 @@ impl LineStreamHandler for Day14 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if let Some(display_frame) = self.display_frame {
             let size = self.terrain_size();
-            println!(
-                "[-] Displaying frame {} of {}",
+            info!(
+                "Displaying frame {} of {}",
                 display_frame,
                 lcm(size.0, size.1)
             );
             let mut display_clone = (*self).clone();
             display_clone.step_all(display_frame);
-            println!("{}", display_clone.compute_histogram());
+            debug!("\n{}", display_clone.compute_histogram());
+        }
+        if let Some(path) = &self.record {
+            self.record(path)?;
         }
         self.step_all(100);
         let (width, height) = self.terrain_size();
@@ -164,15 +200,15 @@ impl LineStreamHandler for Day14 {
                 (_, _) => {}
             }
         }
-        println!(
-            "[{}] Safety factor: {}",
-            SILVER_ANSI,
-            count_tl * count_tr * count_bl * count_br
-        );
-        Ok(())
+        Ok(Answers::new().with_silver("Safety factor", count_tl * count_tr * count_bl * count_br))
     }
 }
 
-pub fn new(display_frame: Option<i64>) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(14, "Restroom Redoubt", Day14::new(display_frame)))
+pub fn new(display_frame: Option<i64>, record: Option<PathBuf>) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day14::new(display_frame, record)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 14, "Restroom Redoubt", Parts::SilverOnly, |args| {
+    new(args.value("frame")?, args.value(Param::RECORD.name)?)
+})
+.with_params(&[Param::value("frame", 'f'), Param::RECORD]);
@@ -1,12 +1,19 @@
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult, Write},
+    path::PathBuf,
+    time::Duration,
 };
 
+use log::debug;
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::LineStreamHandlerOnce,
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
+    frames::{FrameRecorder, Rgb},
+    line_stream::SectionedHandler,
     point::{Direction2, Point2},
+    stepper::{self, Steppable},
     terrain::Terrain,
 };
 
@@ -20,34 +27,55 @@ enum Cell {
     Robot,
 }
 
-impl Display for Cell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_char(match self {
+impl Cell {
+    fn glyph(&self) -> char {
+        match self {
             Cell::Free => '.',
             Cell::Wall => '#',
             Cell::Box => 'O',
             Cell::BoxLeft => '[',
             Cell::BoxRight => ']',
             Cell::Robot => '@',
-        })
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Cell::Free => Rgb(24, 24, 24),
+            Cell::Wall => Rgb(128, 128, 128),
+            Cell::Box | Cell::BoxLeft | Cell::BoxRight => Rgb(181, 126, 60),
+            Cell::Robot => Rgb(255, 48, 48),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_char(self.glyph())
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Day15 {
     terrain: Terrain<Cell>,
     robot_position: Option<Point2<usize>>,
+    moves: Vec<Direction2>,
+    next_move: usize,
     upscale: bool,
-    verbose: bool,
+    stepper: bool,
+    record: Option<PathBuf>,
 }
 
 impl Day15 {
-    fn new(upscale: bool, verbose: bool) -> Self {
+    fn new(upscale: bool, stepper: bool, record: Option<PathBuf>) -> Self {
         Self {
             terrain: Terrain::new(),
             robot_position: None,
+            moves: Vec::new(),
+            next_move: 0,
             upscale,
-            verbose,
+            stepper,
+            record,
         }
     }
 
@@ -58,7 +86,7 @@ impl Day15 {
 
         self.push(pt, towards, false);
 
-        return true;
+        true
     }
 
     fn can_push(&self, pt: Point2<usize>, towards: Direction2, second_half: bool) -> bool {
@@ -86,21 +114,17 @@ impl Day15 {
 
         if !second_half && matches!(towards, Direction2::Up | Direction2::Down) {
             match cell {
-                Cell::BoxLeft => {
-                    if !self.can_push(pt.next_right(), towards, true) {
-                        return false;
-                    }
+                Cell::BoxLeft if !self.can_push(pt.next_right(), towards, true) => {
+                    return false;
                 }
-                Cell::BoxRight => {
-                    if !self.can_push(pt.next_left(), towards, true) {
-                        return false;
-                    }
+                Cell::BoxRight if !self.can_push(pt.next_left(), towards, true) => {
+                    return false;
                 }
                 _ => {}
             }
         }
 
-        return true;
+        true
     }
 
     fn push(&mut self, pt: Point2<usize>, towards: Direction2, second_half: bool) {
@@ -141,32 +165,21 @@ impl Day15 {
         }
         sum
     }
-}
 
-struct Day15Terrain(Day15);
-
-impl LineStreamHandlerOnce for Day15Terrain {
-    fn update(
-        mut self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
-        if line.is_empty() {
-            return Ok(Box::new(Day15Moves(self.0)));
-        }
-
-        let mut row = self.0.terrain.new_row();
+    fn add_row(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
+        let mut row = self.terrain.new_row();
         for ch in line.chars() {
             let cell = match ch {
                 '#' => Cell::Wall,
                 '.' => Cell::Free,
                 'O' => Cell::Box,
                 '@' => {
-                    self.0.robot_position = Some(Point2(row.len(), self.0.terrain.height()));
+                    self.robot_position = Some(Point2(row.len(), self.terrain.height()));
                     Cell::Robot
                 }
                 _ => panic!(),
             };
-            if self.0.upscale {
+            if self.upscale {
                 row.push(match cell {
                     Cell::Box => Cell::BoxLeft,
                     _ => cell,
@@ -180,23 +193,12 @@ impl LineStreamHandlerOnce for Day15Terrain {
                 row.push(cell);
             }
         }
-        self.0.terrain.push_row(row);
-
-        Ok(self)
-    }
+        self.terrain.push_row(row)?;
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        unreachable!()
+        Ok(())
     }
-}
-
-struct Day15Moves(Day15);
 
-impl LineStreamHandlerOnce for Day15Moves {
-    fn update(
-        mut self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
+    fn add_moves(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         for ch in line.chars() {
             let dir = match ch {
                 '<' => Direction2::Left,
@@ -205,39 +207,85 @@ impl LineStreamHandlerOnce for Day15Moves {
                 'v' => Direction2::Down,
                 _ => panic!(),
             };
+            self.moves.push(dir);
+        }
+
+        Ok(())
+    }
 
-            let robot = self.0.robot_position.unwrap();
-            if self.0.try_push(robot, dir) {
-                self.0.robot_position = Some(robot.next_towards(dir));
+    fn finish(mut self) -> Result<Answers, Box<dyn Error>> {
+        if self.stepper {
+            stepper::interact(self.clone())?;
+        }
+        match self.record.take() {
+            Some(path) => {
+                let mut recorder = FrameRecorder::new(Cell::color)
+                    .with_glyph(Cell::glyph)
+                    .with_delay(Duration::from_millis(20));
+                recorder.record(&self.terrain);
+                while self.step() {
+                    recorder.record(&self.terrain);
+                }
+                recorder.save(&path)?;
             }
+            None => while self.step() {},
         }
+        debug!("Final warehouse:\n{}", self.terrain);
 
-        Ok(self)
+        Ok(Answers::new().with_part(
+            self.upscale,
+            "Sum of boxes' GPS coordinates",
+            self.box_coord_sum(),
+        ))
     }
+}
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        if self.0.verbose {
-            println!("{}", self.0.terrain);
+impl Steppable for Day15 {
+    type Cell = Cell;
+
+    fn step(&mut self) -> bool {
+        let Some(&dir) = self.moves.get(self.next_move) else {
+            return false;
+        };
+        self.next_move += 1;
+        let robot = self.robot_position.unwrap();
+        if self.try_push(robot, dir) {
+            self.robot_position = Some(robot.next_towards(dir));
         }
+        true
+    }
 
-        println!(
-            "[{}] Sum of boxes' GPS coordinates: {}",
-            if self.0.upscale {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.0.box_coord_sum()
-        );
+    fn render(&self) -> Terrain<Cell> {
+        self.terrain.clone()
+    }
 
-        Ok(())
+    fn status(&self) -> String {
+        format!(
+            "move {}/{}{}, GPS coordinates sum {}",
+            self.next_move,
+            self.moves.len(),
+            match self.next_move.checked_sub(1) {
+                Some(last) => format!(" {:?}", self.moves[last]),
+                None => String::new(),
+            },
+            self.box_coord_sum()
+        )
     }
 }
 
-pub fn new(gold: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new_once(
-        15,
-        "Warehouse Woes",
-        Day15Terrain(Day15::new(gold, verbose)),
+pub fn new(gold: bool, stepper: bool, record: Option<PathBuf>) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(
+        SectionedHandler::new(Day15::new(gold, stepper, record), Day15::finish)
+            .with_section(Day15::add_row)
+            .with_section(Day15::add_moves),
     ))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 15, "Warehouse Woes", Parts::Selectable, |args| {
+    new(
+        args.flag("gold"),
+        args.flag(Param::STEP.name),
+        args.value(Param::RECORD.name)?,
+    )
+})
+.with_params(&[Param::STEP, Param::RECORD]);
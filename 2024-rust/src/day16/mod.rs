@@ -1,84 +1,61 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashSet,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult, Write},
-    mem::replace,
+    path::PathBuf,
 };
 
+use log::debug;
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::LineStreamHandler,
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
+    error::AocError,
+    frames::{FrameRecorder, Rgb},
     point::{Direction2, Point2},
-    terrain::Terrain,
+    search::{dijkstra, SearchSpace},
+    terrain::{Markers, Terrain},
 };
 
 const MOVE_COST: usize = 1;
 const TURN_COST: usize = 1000;
+const RECORDED_FRAMES: usize = 100;
 
 #[derive(Debug)]
 struct Cell {
     wall: bool,
-    optimal: std::cell::Cell<bool>,
-    min_cost_left: Option<usize>,
-    min_cost_up: Option<usize>,
-    min_cost_right: Option<usize>,
-    min_cost_down: Option<usize>,
+    optimal: bool,
+    min_cost: Option<(usize, Direction2)>,
 }
 
 impl Cell {
     fn new(wall: bool) -> Self {
         Self {
             wall,
-            optimal: std::cell::Cell::new(false),
-            min_cost_left: None,
-            min_cost_up: None,
-            min_cost_right: None,
-            min_cost_down: None,
-        }
-    }
-
-    fn min_cost_facing(&self, facing: Direction2) -> &Option<usize> {
-        match facing {
-            Direction2::Right => &self.min_cost_right,
-            Direction2::Down => &self.min_cost_down,
-            Direction2::Left => &self.min_cost_left,
-            Direction2::Up => &self.min_cost_up,
+            optimal: false,
+            min_cost: None,
         }
     }
+}
 
-    fn min_cost_facing_mut(&mut self, facing: Direction2) -> &mut Option<usize> {
-        match facing {
-            Direction2::Right => &mut self.min_cost_right,
-            Direction2::Down => &mut self.min_cost_down,
-            Direction2::Left => &mut self.min_cost_left,
-            Direction2::Up => &mut self.min_cost_up,
-        }
-    }
+impl TryFrom<char> for Cell {
+    type Error = AocError;
 
-    fn min_cost(&self) -> Option<(usize, Direction2)> {
-        let mut best = None;
-        for dir in Direction2::all() {
-            best = match (best, self.min_cost_facing(dir)) {
-                (None, None) => None,
-                (None, Some(cost)) => Some((*cost, dir)),
-                (Some(previous), None) => Some(previous),
-                (Some(previous), Some(cost)) => Some(if *cost < previous.0 {
-                    (*cost, dir)
-                } else {
-                    previous
-                }),
-            };
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::new(true)),
+            '.' | 'S' | 'E' => Ok(Self::new(false)),
+            _ => Err(AocError::invalid_input("unknown maze cell")),
         }
-        best
     }
 }
 
-impl Display for Cell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let ch = if self.wall {
+impl Cell {
+    fn glyph(&self) -> char {
+        if self.wall {
             '#'
         } else {
-            match self.min_cost() {
+            match self.min_cost {
                 Some((_, facing)) => match facing {
                     Direction2::Right => '>',
                     Direction2::Down => 'v',
@@ -87,8 +64,26 @@ impl Display for Cell {
                 },
                 None => '.',
             }
-        };
-        if self.optimal.get() {
+        }
+    }
+
+    /// Shades reached cells from blue to orange by their cost, up to `score`.
+    fn color(&self, score: usize) -> Rgb {
+        match self.min_cost {
+            _ if self.wall => Rgb(96, 96, 96),
+            _ if self.optimal => Rgb(0, 204, 0),
+            Some((cost, _)) => {
+                Rgb(32, 32, 160).mix(Rgb(255, 160, 0), cost as f64 / score.max(1) as f64)
+            }
+            None => Rgb(16, 16, 16),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let ch = self.glyph();
+        if self.optimal {
             write!(f, "\x1B[38;2;0;204;0m{}\x1B[m", ch)
         } else {
             f.write_char(ch)
@@ -98,156 +93,99 @@ impl Display for Cell {
 
 struct Day16 {
     terrain: Terrain<Cell>,
-    start: Option<Point2<usize>>,
-    end: Option<Point2<usize>>,
-    verbose: bool,
+    start: Point2<usize>,
+    end: Point2<usize>,
+    record: Option<PathBuf>,
 }
 
-fn update_next(
-    next: &mut HashMap<(Point2<usize>, Direction2), usize>,
-    pt: Point2<usize>,
-    facing: Direction2,
-    cost: usize,
-) {
-    next.entry((pt.next_towards(facing), facing))
-        .and_modify(|best| *best = (*best).min(cost))
-        .or_insert(cost);
+impl SearchSpace for Day16 {
+    type State = (Point2<usize>, Direction2);
+
+    fn neighbors(
+        &self,
+        &(pt, facing): &Self::State,
+    ) -> impl IntoIterator<Item = (Self::State, usize)> {
+        let ahead = pt.next_towards(facing);
+        [
+            (!self.terrain[ahead].wall).then_some(((ahead, facing), MOVE_COST)),
+            Some(((pt, facing.clockwise()), TURN_COST)),
+            Some(((pt, facing.counterclockwise()), TURN_COST)),
+        ]
+        .into_iter()
+        .flatten()
+    }
 }
 
 impl Day16 {
-    fn new(verbose: bool) -> Self {
-        Self {
-            terrain: Terrain::new(),
-            start: None,
-            end: None,
-            verbose,
-        }
+    fn new(
+        terrain: Terrain<Cell>,
+        markers: &Markers,
+        record: Option<PathBuf>,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            terrain,
+            start: markers.one('S')?,
+            end: markers.one('E')?,
+            record,
+        })
     }
 
-    fn calculate_costs(&mut self) {
-        let mut next = HashMap::new();
-        next.insert((self.start.unwrap(), Direction2::Right), 0usize);
-        while !next.is_empty() {
-            for ((pt, facing), cost) in replace(&mut next, HashMap::new()) {
-                if self.terrain[pt].wall {
-                    continue;
-                }
-
-                let min_cost = self.terrain[pt].min_cost_facing_mut(facing);
-                let better = match min_cost {
-                    Some(min) => cost < *min,
-                    None => true,
-                };
-
-                if !better {
-                    continue;
+    fn finish(mut self) -> Result<Answers, Box<dyn Error>> {
+        let result = dijkstra(&self, [(self.start, Direction2::Right)], |&(pt, _)| {
+            pt == self.end
+        });
+        let score = result
+            .goal_cost()
+            .ok_or_else(|| AocError::unsolvable("the end cannot be reached"))?;
+        let optimal_cells: HashSet<Point2<usize>> = result
+            .on_paths(result.goals())
+            .into_iter()
+            .map(|(pt, _)| pt)
+            .collect();
+
+        // Cells are filled in by increasing cost, so that a recording shows
+        // the search spreading out.
+        let mut costs = result.costs().collect::<Vec<_>>();
+        costs.sort_unstable_by_key(|&(_, cost)| cost);
+        let mut recorder = self.record.is_some().then(|| {
+            FrameRecorder::new(move |cell: &Cell| cell.color(score)).with_glyph(Cell::glyph)
+        });
+        let batch = costs.len().div_ceil(RECORDED_FRAMES).max(1);
+        for (i, &(&(pt, facing), cost)) in costs.iter().enumerate() {
+            let cell = &mut self.terrain[pt];
+            if cell.min_cost.is_none_or(|(min, _)| cost < min) {
+                cell.min_cost = Some((cost, facing));
+            }
+            if let Some(recorder) = &mut recorder {
+                if (i + 1) % batch == 0 {
+                    recorder.record(&self.terrain);
                 }
-
-                *min_cost = Some(cost);
-
-                update_next(&mut next, pt, facing, cost + MOVE_COST);
-                update_next(
-                    &mut next,
-                    pt,
-                    facing.clockwise(),
-                    cost + MOVE_COST + TURN_COST,
-                );
-                update_next(
-                    &mut next,
-                    pt,
-                    facing.counterclockwise(),
-                    cost + MOVE_COST + TURN_COST,
-                );
             }
         }
-    }
-
-    fn end_cost(&self) -> (usize, Direction2) {
-        self.terrain[self.end.unwrap()].min_cost().unwrap()
-    }
-
-    fn collect_optimal(
-        &self,
-        pt: Point2<usize>,
-        backtracked_cost: (usize, Direction2),
-        dest: &mut HashSet<Point2<usize>>,
-    ) {
-        if !dest.insert(pt) {
-            return;
+        for pt in &optimal_cells {
+            self.terrain[*pt].optimal = true;
         }
-        let cell = &self.terrain[pt];
-        cell.optimal.set(true);
-        if cell.min_cost().unwrap().0 < MOVE_COST {
-            return;
+        if let (Some(path), Some(recorder)) = (&self.record, &mut recorder) {
+            recorder.record(&self.terrain);
+            recorder.save(path)?;
         }
-        for dir in Direction2::all() {
-            let min = match *cell.min_cost_facing(dir) {
-                Some(cost) => cost,
-                None => {
-                    continue;
-                }
-            };
-            let turn_cost = if dir == backtracked_cost.1 {
-                0
-            } else {
-                TURN_COST
-            };
-            let min_aligned = min + turn_cost;
-            if min_aligned == backtracked_cost.0 {
-                self.collect_optimal(
-                    pt.next_towards(-dir),
-                    (backtracked_cost.0 - turn_cost - MOVE_COST, dir),
-                    dest,
-                );
-            }
-        }
-    }
+        debug!("Optimal paths:\n{}", self.terrain);
 
-    fn count_optimal_cells(&mut self) -> usize {
-        let mut optimal_cells = HashSet::new();
-        self.collect_optimal(self.end.unwrap(), self.end_cost(), &mut optimal_cells);
-        optimal_cells.len()
+        Ok(Answers::new()
+            .with_silver("Optimal score", score)
+            .with_gold("Cells on optimal paths", optimal_cells.len()))
     }
 }
 
-impl LineStreamHandler for Day16 {
-    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
-        let mut row = self.terrain.new_row();
-        for (ch, i) in line.chars().zip(0usize..) {
-            row.push(Cell::new(match ch {
-                '#' => true,
-                '.' => false,
-                'E' => {
-                    self.end = Some(Point2(i, self.terrain.height()));
-                    false
-                }
-                'S' => {
-                    self.start = Some(Point2(i, self.terrain.height()));
-                    false
-                }
-                _ => panic!(),
-            }));
-        }
-        self.terrain.push_row(row);
-        Ok(())
-    }
-
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        self.calculate_costs();
-        let optimal_cells = self.count_optimal_cells();
-        if self.verbose {
-            println!("{}", self.terrain);
-        }
-        println!(
-            "[{}] Optimal score:          {}",
-            SILVER_ANSI,
-            self.end_cost().0
-        );
-        println!("[{}] Cells on optimal paths: {}", GOLD_ANSI, optimal_cells);
-        Ok(())
-    }
+pub fn new(record: Option<PathBuf>) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(
+        Terrain::builder()
+            .with_markers("SE")
+            .into_handler(|terrain, markers| Day16::new(terrain, &markers, record)?.finish()),
+    ))
 }
 
-pub fn new(verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(16, "Reindeer Maze", Day16::new(verbose)))
-}
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 16, "Reindeer Maze", Parts::Both, |args| {
+    new(args.value(Param::RECORD.name)?)
+})
+.with_params(&[Param::RECORD]);
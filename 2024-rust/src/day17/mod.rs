@@ -1,16 +1,17 @@
 use std::{error::Error, mem::take};
 
+use itertools::Itertools;
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
+    parse::{labelled, list},
 };
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::complete::{char, u64, u8},
+    character::complete::{u64, u8},
     combinator::map,
-    multi::separated_list1,
-    sequence::preceded,
 };
 
 mod vm;
@@ -76,19 +77,10 @@ impl LineStreamHandler for Day17 {
         match parse_full_string(
             line,
             alt((
-                map(preceded(tag("Register A: "), u64), |value| {
-                    Instruction::A(value)
-                }),
-                map(preceded(tag("Register B: "), u64), |value| {
-                    Instruction::B(value)
-                }),
-                map(preceded(tag("Register C: "), u64), |value| {
-                    Instruction::C(value)
-                }),
-                map(
-                    preceded(tag("Program: "), separated_list1(char(','), u8)),
-                    |value| Instruction::Program(value),
-                ),
+                map(labelled("Register A", u64), Instruction::A),
+                map(labelled("Register B", u64), Instruction::B),
+                map(labelled("Register C", u64), Instruction::C),
+                map(labelled("Program", list(u8)), Instruction::Program),
             )),
         )? {
             Instruction::A(value) => {
@@ -108,14 +100,14 @@ impl LineStreamHandler for Day17 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let program = take(&mut self.program).unwrap();
         if self.gold {
             // Corners have been cut, this may not be correct in all cases.
             let b = self.b.unwrap();
             let c = self.c.unwrap();
             let a = search_for_quine_a(b, c, &program, program.len() - 1, 0)?;
-            println!("[{}] Register A: {}", GOLD_ANSI, a.unwrap());
+            Ok(Answers::new().with_gold("Register A", a.unwrap()))
         } else {
             let mut vm = vm::VirtualMachine::new(
                 self.a.unwrap(),
@@ -125,20 +117,19 @@ impl LineStreamHandler for Day17 {
             );
             vm.try_run_to_completion()?;
             let output = vm.into_output();
-            print!("[{}] Output: ", SILVER_ANSI);
-            for (out, i) in output.into_iter().zip(0usize..) {
-                if i > 0 {
-                    print!(",{}", out);
-                } else {
-                    print!("{}", out);
-                }
-            }
-            println!();
+            Ok(Answers::new().with_silver("Output", output.into_iter().join(",")))
         }
-        Ok(())
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(17, "Chronospatial Computer", Day17::new(gold)))
+    Ok(Day::new(Day17::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(
+    2024,
+    17,
+    "Chronospatial Computer",
+    Parts::Selectable,
+    |args| new(args.flag("gold")),
+);
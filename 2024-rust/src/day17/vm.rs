@@ -3,6 +3,8 @@ use std::{
     ops::{BitAnd, BitXorAssign, Shr, ShrAssign},
 };
 
+use aoc_common_rs::error::AocError;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Instruction {
     Adv,
@@ -81,7 +83,7 @@ impl TryFrom<u8> for Instruction {
             5 => Ok(Self::Out),
             6 => Ok(Self::Bdv),
             7 => Ok(Self::Cdv),
-            _ => Err(Box::from("invalid instruction")),
+            _ => Err(AocError::invalid_input("invalid instruction").into()),
         }
     }
 }
@@ -113,11 +115,11 @@ impl TryFrom<u8> for Operand {
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0 | 1 | 2 | 3 => Ok(Self::Literal(value)),
+            0..=3 => Ok(Self::Literal(value)),
             4 => Ok(Self::A),
             5 => Ok(Self::B),
             6 => Ok(Self::C),
-            _ => Err(Box::from("invalid combo operand")),
+            _ => Err(AocError::invalid_input("invalid combo operand").into()),
         }
     }
 }
@@ -170,7 +172,7 @@ where
         let instruction = Instruction::try_from(self.program[self.ip])?;
         let operand = instruction.try_decode_operand(self.program[self.ip + 1])?;
         self.ip += 2;
-        instruction.execute(self, operand.evaluate(&self))?;
+        instruction.execute(self, operand.evaluate(self))?;
         Ok(true)
     }
 
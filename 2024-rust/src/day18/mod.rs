@@ -1,16 +1,14 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
+    parse::point2,
     point::Point2,
     terrain::Terrain,
 };
-use nom::{
-    character::complete::{char, u64},
-    combinator::map,
-    sequence::separated_pair,
-};
 
 #[derive(Debug)]
 struct Cell {
@@ -45,19 +43,14 @@ impl Day18 {
 
 impl LineStreamHandler for Day18 {
     fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
-        let pt = parse_full_string(
-            line,
-            map(separated_pair(u64, char(','), u64), |(x, y)| {
-                Point2(x as usize, y as usize)
-            }),
-        )?;
+        let pt = parse_full_string(line, point2::<usize>())?;
         self.terrain[pt].corrupted_after = self.corruptions;
         self.corruptions += 1;
 
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let exit = Point2(self.terrain.width() - 1, self.terrain.height() - 1);
         if self.gold {
             for corruptions in (0..self.corruptions).rev() {
@@ -68,22 +61,18 @@ impl LineStreamHandler for Day18 {
                 );
 
                 if self.terrain[exit].min_cost != usize::MAX {
-                    println!("[-] Maximum time to reach the exit: {}", corruptions);
-                    println!(
-                        "[-] Minimum number of steps:        {}",
-                        self.terrain[exit].min_cost
-                    );
-                    for pt in self.terrain.points() {
-                        if self.terrain[pt].corrupted_after == corruptions {
-                            println!(
-                                "[{}] Coordinates of byte:            {},{}",
-                                GOLD_ANSI, pt.0, pt.1
-                            );
-                        }
-                    }
-                    break;
+                    let byte = self
+                        .terrain
+                        .points()
+                        .find(|pt| self.terrain[*pt].corrupted_after == corruptions)
+                        .ok_or_else(|| AocError::unsolvable("blocking byte not found"))?;
+                    return Ok(Answers::new()
+                        .with_gold("Coordinates of byte", format!("{},{}", byte.0, byte.1))
+                        .with_extra("Maximum time to reach the exit", corruptions)
+                        .with_extra("Minimum number of steps", self.terrain[exit].min_cost));
                 }
             }
+            Err(AocError::unsolvable("the exit is always reachable").into())
         } else {
             self.terrain.flood_fill_mut(
                 Point2(0, 0),
@@ -93,20 +82,15 @@ impl LineStreamHandler for Day18 {
                 |terrain, level, pt| terrain[pt].min_cost = level,
             );
 
-            println!(
-                "[{}] Minimum number of steps: {}",
-                SILVER_ANSI, self.terrain[exit].min_cost
-            );
+            Ok(Answers::new().with_silver("Minimum number of steps", self.terrain[exit].min_cost))
         }
-
-        Ok(())
     }
 }
 
 pub fn new(sample: bool, gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(
-        18,
-        "RAM Run",
-        Day18::new(if sample { 7 } else { 71 }, gold),
-    ))
+    Ok(Day::new(Day18::new(if sample { 7 } else { 71 }, gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 18, "RAM Run", Parts::Selectable, |args| {
+    new(args.flag("sample"), args.flag("gold"))
+});
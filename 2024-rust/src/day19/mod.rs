@@ -1,12 +1,11 @@
 use std::{cell::RefCell, collections::HashMap, error::Error, ops::Range};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::LineStreamHandlerOnce,
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    line_stream::SectionedHandler,
 };
 
-struct Day19Start();
-
 fn insert_pattern(
     patterns: &mut HashMap<char, Vec<Range<usize>>>,
     rest: &str,
@@ -14,35 +13,11 @@ fn insert_pattern(
     len: usize,
 ) {
     let head = rest.chars().next().unwrap();
-    let pats = patterns.entry(head).or_insert_with(|| Vec::new());
+    let pats = patterns.entry(head).or_default();
     pats.push(start..(start + len));
 }
 
-impl LineStreamHandlerOnce for Day19Start {
-    fn update(
-        self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
-        let mut patterns = HashMap::new();
-        let mut start = 0usize;
-        let mut rest = line;
-        while let Some(separator) = rest.find(", ") {
-            if separator > 0 {
-                insert_pattern(&mut patterns, rest, start, separator);
-            }
-            start += separator + 2;
-            rest = &rest[(separator + 2)..];
-        }
-        insert_pattern(&mut patterns, rest, start, rest.len());
-        Ok(Box::new(Day19::new(line.into(), patterns)))
-    }
-
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        unreachable!()
-    }
-}
-
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct Day19 {
     line: String,
     patterns: HashMap<char, Vec<Range<usize>>>,
@@ -52,14 +27,19 @@ struct Day19 {
 }
 
 impl Day19 {
-    fn new(line: String, patterns: HashMap<char, Vec<Range<usize>>>) -> Day19 {
-        Self {
-            line,
-            patterns,
-            matching_inputs: 0,
-            matches: 0,
-            match_cache: RefCell::new(HashMap::new()),
+    fn add_patterns(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
+        let mut start = self.line.len();
+        let mut rest = line;
+        while let Some(separator) = rest.find(", ") {
+            if separator > 0 {
+                insert_pattern(&mut self.patterns, rest, start, separator);
+            }
+            start += separator + 2;
+            rest = &rest[(separator + 2)..];
         }
+        insert_pattern(&mut self.patterns, rest, start, rest.len());
+        self.line.push_str(line);
+        Ok(())
     }
 
     fn count_matches(&self, input: &str) -> usize {
@@ -78,7 +58,7 @@ impl Day19 {
                 if len > input.len() {
                     continue;
                 }
-                if &input[0..len] == &self.line[pat.clone()] {
+                if input[0..len] == self.line[pat.clone()] {
                     matches += self.count_matches(&input[len..]);
                 }
             }
@@ -90,13 +70,8 @@ impl Day19 {
             0
         }
     }
-}
 
-impl LineStreamHandlerOnce for Day19 {
-    fn update(
-        mut self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
+    fn add_design(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         if !line.is_empty() {
             let matches = self.count_matches(line);
             if matches > 0 {
@@ -104,19 +79,22 @@ impl LineStreamHandlerOnce for Day19 {
             }
             self.matches += matches;
         }
-        Ok(self)
+        Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Possible designs: {}",
-            SILVER_ANSI, self.matching_inputs
-        );
-        println!("[{}] Different ways:   {}", GOLD_ANSI, self.matches);
-        Ok(())
+    fn finish(self) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Possible designs", self.matching_inputs)
+            .with_gold("Different ways", self.matches))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new_once(19, "Linen Layout", Day19Start()))
+    Ok(Day::new(
+        SectionedHandler::new(Day19::default(), Day19::finish)
+            .with_section(Day19::add_patterns)
+            .with_section(Day19::add_design),
+    ))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 19, "Linen Layout", Parts::Both, |_| new());
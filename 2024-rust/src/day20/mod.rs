@@ -1,24 +1,31 @@
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult, Write},
+    path::PathBuf,
 };
 
 use aoc_common_rs::{
-    day::{Day, SILVER_ANSI},
-    line_stream::LineStreamHandler,
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
+    error::AocError,
+    frames::{FrameRecorder, Rgb},
     point::{Direction2, Point2},
-    terrain::Terrain,
+    terrain::{Markers, Terrain},
 };
 
+const RECORDED_FRAMES: usize = 100;
+const DEFAULT_THRESHOLD: usize = 100;
+
 #[derive(Debug)]
 struct Track {
     wall: bool,
     cost: usize,
+    cheat: bool,
 }
 
 impl Display for Track {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_char(if self.wall { '#' } else { '.' })
+        f.write_char(self.glyph())
     }
 }
 
@@ -27,6 +34,41 @@ impl Track {
         Self {
             wall,
             cost: usize::MAX,
+            cheat: false,
+        }
+    }
+
+    fn glyph(&self) -> char {
+        match self {
+            Track { cheat: true, .. } => 'C',
+            Track { wall: true, .. } => '#',
+            _ => '.',
+        }
+    }
+
+    /// Shades the track from blue to orange by its cost, up to `longest`.
+    fn color(&self, longest: usize) -> Rgb {
+        match self {
+            Track { cheat: true, .. } => Rgb(255, 0, 255),
+            Track { wall: true, .. } => Rgb(96, 96, 96),
+            Track {
+                cost: usize::MAX, ..
+            } => Rgb(16, 16, 16),
+            Track { cost, .. } => {
+                Rgb(32, 32, 160).mix(Rgb(255, 160, 0), *cost as f64 / longest.max(1) as f64)
+            }
+        }
+    }
+}
+
+impl TryFrom<char> for Track {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::new(true)),
+            '.' | 'S' | 'E' => Ok(Self::new(false)),
+            _ => Err(AocError::invalid_input("unknown track cell")),
         }
     }
 }
@@ -63,27 +105,52 @@ impl CheatReport {
 #[derive(Debug)]
 struct Day20 {
     terrain: Terrain<Track>,
-    start: Option<Point2<usize>>,
-    end: Option<Point2<usize>>,
+    start: Point2<usize>,
     max_cheat_distance: usize,
+    threshold: usize,
+    record: Option<PathBuf>,
 }
 
 impl Day20 {
-    fn new(gold: bool) -> Self {
-        Self {
-            terrain: Terrain::new(),
-            start: None,
-            end: None,
+    fn new(
+        terrain: Terrain<Track>,
+        markers: &Markers,
+        gold: bool,
+        threshold: usize,
+        record: Option<PathBuf>,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            terrain,
+            start: markers.one('S')?,
             max_cheat_distance: if gold { 20 } else { 2 },
-        }
+            threshold,
+            record,
+        })
     }
 
-    fn calculate_costs(&mut self) {
-        self.terrain.flood_fill_mut(
-            self.start.unwrap(),
-            |terrain, _, _, _, pt| !terrain[pt].wall,
-            |terrain, level, pt| terrain[pt].cost = level,
-        );
+    /// Fills in the costs from the start, by increasing cost so that the
+    /// returned recording (if one was asked for) shows them spreading out.
+    fn calculate_costs(&mut self) -> Option<FrameRecorder<Track>> {
+        let costs = self.terrain.distances_from([self.start], |_, to| !to.wall);
+        let mut reached = costs
+            .points()
+            .filter_map(|pt| costs[pt].map(|cost| (cost, pt)))
+            .collect::<Vec<_>>();
+        reached.sort_unstable_by_key(|&(cost, _)| cost);
+        let longest = reached.last().map_or(0, |&(cost, _)| cost);
+        let mut recorder = self.record.as_ref().map(|_| {
+            FrameRecorder::new(move |track: &Track| track.color(longest)).with_glyph(Track::glyph)
+        });
+        let batch = reached.len().div_ceil(RECORDED_FRAMES).max(1);
+        for (i, &(cost, pt)) in reached.iter().enumerate() {
+            self.terrain[pt].cost = cost;
+            if let Some(recorder) = &mut recorder {
+                if (i + 1) % batch == 0 {
+                    recorder.record(&self.terrain);
+                }
+            }
+        }
+        recorder
     }
 
     fn next(&self, pt: Point2<usize>) -> Option<(Direction2, Point2<usize>)> {
@@ -116,7 +183,7 @@ impl Day20 {
                     }
                 };
                 let benefit = delta - level;
-                if benefit >= 100 {
+                if benefit >= self.threshold {
                     report.interesting += 1;
                 }
                 if benefit > report.best_benefit {
@@ -128,50 +195,48 @@ impl Day20 {
         );
         report
     }
-}
-
-impl LineStreamHandler for Day20 {
-    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
-        let mut row = self.terrain.new_row();
-        for (ch, i) in line.chars().zip(0usize..) {
-            row.push(Track::new(match ch {
-                '#' => true,
-                '.' => false,
-                'S' => {
-                    self.start = Some(Point2(i, self.terrain.height()));
-                    false
-                }
-                'E' => {
-                    self.end = Some(Point2(i, self.terrain.height()));
-                    false
-                }
-                _ => panic!(),
-            }));
-        }
-        self.terrain.push_row(row);
-        Ok(())
-    }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        self.calculate_costs();
-        let mut report = CheatReport::new_empty(self.start.unwrap());
-        let mut maybe_pt = self.start;
+    fn finish(mut self) -> Result<Answers, Box<dyn Error>> {
+        let recorder = self.calculate_costs();
+        let mut report = CheatReport::new_empty(self.start);
+        let mut maybe_pt = Some(self.start);
         while let Some(pt) = maybe_pt {
             report = report.merge(self.find_cheats_from(pt, self.max_cheat_distance));
             maybe_pt = self.next(pt).map(|(_, pt)| pt);
         }
-        println!(
-            "[{}] Interesting cheats: {}",
-            SILVER_ANSI, report.interesting
-        );
-        println!(
-            "[-] Best cheat:         {} -> {}, saves {} ps",
-            report.best_from, report.best_to, report.best_benefit,
-        );
-        Ok(())
+        if let (Some(path), Some(mut recorder)) = (&self.record, recorder) {
+            self.terrain[report.best_from].cheat = true;
+            self.terrain[report.best_to].cheat = true;
+            recorder.record(&self.terrain);
+            recorder.save(path)?;
+        }
+        Ok(Answers::new()
+            .with_part(
+                self.max_cheat_distance > 2,
+                "Interesting cheats",
+                report.interesting,
+            )
+            .with_extra(
+                "Best cheat",
+                format!(
+                    "{} -> {}, saves {} ps",
+                    report.best_from, report.best_to, report.best_benefit
+                ),
+            ))
     }
 }
 
-pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(20, "Race Condition", Day20::new(gold)))
+pub fn new(gold: bool, threshold: usize, record: Option<PathBuf>) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Terrain::builder().with_markers("S").into_handler(
+        move |terrain, markers| Day20::new(terrain, &markers, gold, threshold, record)?.finish(),
+    )))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 20, "Race Condition", Parts::Selectable, |args| {
+    new(
+        args.flag("gold"),
+        args.value("threshold")?.unwrap_or(DEFAULT_THRESHOLD),
+        args.value(Param::RECORD.name)?,
+    )
+})
+.with_params(&[Param::value("threshold", 'm'), Param::RECORD]);
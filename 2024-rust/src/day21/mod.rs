@@ -1,7 +1,8 @@
 use std::{collections::HashMap, error::Error};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     digit::Digit,
     line_stream::LineStreamHandler,
     ok_or_continue,
@@ -49,16 +50,15 @@ impl LineStreamHandler for Day21 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of complexities: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            self.sum_of_complexities
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.gold, "Sum of complexities", self.sum_of_complexities))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(21, "Keypad Conundrum", Day21::new(gold)))
+    Ok(Day::new(Day21::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 21, "Keypad Conundrum", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -3,7 +3,7 @@ use std::{
     collections::{hash_map::Entry, HashMap},
     fmt::{Display, Formatter, Result as FmtResult, Write},
     hash::Hash,
-    mem::replace,
+    mem::take,
     ops::{Add, AddAssign},
 };
 
@@ -164,7 +164,7 @@ pub enum NumpadKey {
 
 impl NumpadKey {
     fn digit(value: u8) -> Option<Self> {
-        Digit::new(value).map(|digit| Self::Digit(digit))
+        Digit::new(value).map(Self::Digit)
     }
 }
 
@@ -182,10 +182,10 @@ impl Key for NumpadKey {
     fn next_towards(self, direction: Direction2) -> Option<Self> {
         match self {
             NumpadKey::Digit(digit) => match (digit.get(), direction) {
-                (1 | 2 | 3 | 4 | 5 | 6, Direction2::Up) => Self::digit(digit.get() + 3),
+                (1..=6, Direction2::Up) => Self::digit(digit.get() + 3),
                 (1 | 2 | 4 | 5 | 7 | 8, Direction2::Right) => Self::digit(digit.get() + 1),
                 (2 | 3 | 5 | 6 | 8 | 9, Direction2::Left) => Self::digit(digit.get() - 1),
-                (4 | 5 | 6 | 7 | 8 | 9, Direction2::Down) => Self::digit(digit.get() - 3),
+                (4..=9, Direction2::Down) => Self::digit(digit.get() - 3),
                 (2, Direction2::Down) => Self::digit(0),
                 (3, Direction2::Down) => Some(Self::A),
                 (0, Direction2::Up) => Self::digit(2),
@@ -248,7 +248,7 @@ where
         let mut best_path_costs = HashMap::new();
         submatrix.insert((from, DpadKey::A), KeyPressCost::zero());
         while !submatrix.is_empty() {
-            for ((to, last), cost_so_far) in replace(&mut submatrix, HashMap::new()) {
+            for ((to, last), cost_so_far) in take(&mut submatrix) {
                 let closing_cost = previous.get(&(last, DpadKey::A)).unwrap();
                 let total_cost = cost_so_far.clone() + closing_cost.clone();
                 matrix
@@ -4,7 +4,8 @@ use std::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     digit::Digit,
     line_stream::{parse_full_string, LineStreamHandler},
 };
@@ -61,23 +62,17 @@ impl LineStreamHandler for Day22 {
             deltas.copy_within(1.., 0);
             let (bananas, delta) = prng.next_with_delta();
             deltas[3] = delta;
-            if deltas.iter().all(|delta| *delta != i8::MIN) {
-                if !seen_sequences.contains(&deltas) {
-                    seen_sequences.insert(deltas);
-                    let total_bananas = self.bananas_by_sequence.entry(deltas).or_insert(0);
-                    *total_bananas += u64::from(bananas);
-                }
+            if deltas.iter().all(|delta| *delta != i8::MIN) && !seen_sequences.contains(&deltas) {
+                seen_sequences.insert(deltas);
+                let total_bananas = self.bananas_by_sequence.entry(deltas).or_insert(0);
+                *total_bananas += u64::from(bananas);
             }
         }
         self.sum += prng.state as u64;
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Sum of 2000th secret numbers: {}",
-            SILVER_ANSI, self.sum
-        );
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut best_bananas = 0;
         let mut best_sequence = [i8::MIN; 4];
         for (sequence, bananas) in self.bananas_by_sequence {
@@ -86,15 +81,15 @@ impl LineStreamHandler for Day22 {
                 best_sequence = sequence;
             }
         }
-        println!(
-            "[{}] Maximum bananas:              {}",
-            GOLD_ANSI, best_bananas
-        );
-        println!("[-] Sequence for max. bananas:    {:?}", best_sequence);
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Sum of 2000th secret numbers", self.sum)
+            .with_gold("Maximum bananas", best_bananas)
+            .with_extra("Sequence for max. bananas", format!("{:?}", best_sequence)))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(22, "Monkey Market", Day22::new()))
+    Ok(Day::new(Day22::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 22, "Monkey Market", Parts::Both, |_| new());
@@ -10,9 +10,13 @@ use std::{
     error::Error,
 };
 
+use itertools::Itertools;
+
 use aoc_common_rs::{
+    answers::Answers,
     cc::TwoCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{parse_full_string, LineStreamHandler},
 };
 
@@ -78,19 +82,20 @@ impl LineStreamHandler for Day23 {
         let (id1, id2) = parse_full_string(line, separated_pair(two_cc(), char('-'), two_cc()))?;
         self.connections
             .entry(id1.min(id2))
-            .or_insert_with(|| HashSet::new())
+            .or_default()
             .insert(id1.max(id2));
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let triples = self.find_triples();
         let triples_with_t = triples
             .iter()
             .filter(|triple| triple.iter().any(|id| id.first() == 't'))
             .count();
-        println!("[{}] Triples with a t: {}", SILVER_ANSI, triples_with_t);
-        println!("[-] Meshes of  3:     {}", triples.len());
+        let mut answers = Answers::new()
+            .with_silver("Triples with a t", triples_with_t)
+            .with_extra("Meshes of  3", triples.len());
         let mut meshes = triples;
         let mut mesh_size = 3usize;
         loop {
@@ -100,25 +105,24 @@ impl LineStreamHandler for Day23 {
             }
             meshes = larger_meshes;
             mesh_size += 1;
-            println!("[-] Meshes of {:2}:     {}", mesh_size, meshes.len());
+            answers.push_extra(format!("Meshes of {:2}", mesh_size), meshes.len());
         }
+        let mut meshes = meshes.into_iter().map(|mesh| mesh.into_iter().join(","));
+        answers = answers.with_gold(
+            "Largest mesh",
+            meshes
+                .next()
+                .ok_or_else(|| AocError::unsolvable("no mesh found"))?,
+        );
         for mesh in meshes {
-            print!("[{}] Largest mesh:     ", GOLD_ANSI);
-            let mut first = true;
-            for id in mesh {
-                if first {
-                    first = false;
-                    print!("{}", id);
-                } else {
-                    print!(",{}", id);
-                }
-            }
-            println!();
+            answers.push_extra("Other largest mesh", mesh);
         }
-        Ok(())
+        Ok(answers)
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(23, "LAN Party", Day23::new()))
+    Ok(Day::new(Day23::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 23, "LAN Party", Parts::Both, |_| new());
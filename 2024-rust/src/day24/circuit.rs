@@ -127,10 +127,7 @@ impl Circuit {
     }
 
     pub fn get(&self, code: ThreeCC) -> Option<Wire> {
-        match self.wires.get(&code) {
-            Some(definition) => Some(*definition),
-            None => None,
-        }
+        self.wires.get(&code).copied()
     }
 
     pub fn remove(&mut self, code: ThreeCC) -> Option<Wire> {
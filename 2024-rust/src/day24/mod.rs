@@ -1,16 +1,15 @@
 use std::error::Error;
 
+use itertools::Itertools;
+
 use aoc_common_rs::{
-    cc::ThreeCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{parse_full_string, LineStreamHandler},
+    parse::{edge, three_cc},
 };
 use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{anychar, char},
-    combinator::map,
-    error::Error as NomError,
+    branch::alt, bytes::complete::tag, character::complete::char, combinator::map,
     sequence::separated_pair,
 };
 
@@ -37,12 +36,6 @@ impl LineStreamHandler for Day24 {
             return Ok(());
         }
 
-        let three_cc = move || {
-            map(
-                (anychar::<&str, NomError<&str>>, anychar, anychar),
-                ThreeCC::from,
-            )
-        };
         let (definition, code) = parse_full_string(
             line,
             alt((
@@ -54,7 +47,7 @@ impl LineStreamHandler for Day24 {
                     ),
                     |(code, value)| (Wire::Literal(value), code),
                 ),
-                separated_pair(
+                edge(
                     alt((
                         map(
                             separated_pair(three_cc(), tag(" AND "), three_cc()),
@@ -69,7 +62,6 @@ impl LineStreamHandler for Day24 {
                             |(op1, op2)| Wire::Xor(op1, op2),
                         ),
                     )),
-                    tag(" -> "),
                     three_cc(),
                 ),
             )),
@@ -78,32 +70,23 @@ impl LineStreamHandler for Day24 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         let mut evaluator = self.circuit.clone();
-        println!("[-] Number on x wires: {}", evaluator.evaluate_number('x'));
-        println!("[-] Number on y wires: {}", evaluator.evaluate_number('y'));
-        println!(
-            "[{}] Number on z wires: {}",
-            SILVER_ANSI,
-            evaluator.evaluate_number('z')
-        );
+        let x = evaluator.evaluate_number('x');
+        let y = evaluator.evaluate_number('y');
+        let z = evaluator.evaluate_number('z');
         let mut errors: Vec<_> = self.circuit.clone().fix_adder().into_iter().collect();
         errors.sort();
-        print!("[{}] Crossed wires:     ", GOLD_ANSI);
-        let mut first = true;
-        for code in errors {
-            if first {
-                first = false;
-                print!("{}", code);
-            } else {
-                print!(",{}", code);
-            }
-        }
-        println!();
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Number on z wires", z)
+            .with_gold("Crossed wires", errors.into_iter().join(","))
+            .with_extra("Number on x wires", x)
+            .with_extra("Number on y wires", y))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(24, "Crossed Wires", Day24::new()))
+    Ok(Day::new(Day24::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 24, "Crossed Wires", Parts::Both, |_| new());
@@ -1,7 +1,8 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
 };
 
@@ -62,7 +63,7 @@ impl LineStreamHandler for Day25 {
         }
 
         if self.current_row == 0 {
-            self.current_is_key = line.chars().next() == Some('.');
+            self.current_is_key = line.starts_with('.');
         } else if self.current_is_key {
             for (ch, i) in line.chars().zip(0..COLUMNS) {
                 if ch == '#' && self.current[i] == u8::MAX {
@@ -80,7 +81,7 @@ impl LineStreamHandler for Day25 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.flush();
         let mut pairs = 0usize;
         for key in self.keys.iter() {
@@ -90,11 +91,12 @@ impl LineStreamHandler for Day25 {
                 }
             }
         }
-        println!("[{}] Fitting lock/key pairs: {}", SILVER_ANSI, pairs);
-        Ok(())
+        Ok(Answers::new().with_silver("Fitting lock/key pairs", pairs))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(25, "Code Chronicle", Day25::new()))
+    Ok(Day::new(Day25::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2024, 25, "Code Chronicle", Parts::SilverOnly, |_| new());
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common_rs::sample_tests::emit("../2025-data").unwrap();
+}
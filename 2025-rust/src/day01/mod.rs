@@ -1,9 +1,13 @@
 use std::error::Error;
 use std::fmt::{Display, Write};
 
-use aoc_common_rs::day::{GOLD_ANSI, SILVER_ANSI};
 use aoc_common_rs::line_stream::parse_full_string;
-use aoc_common_rs::{day::Day, line_stream::LineStreamHandler};
+use aoc_common_rs::{
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    line_stream::LineStreamHandler,
+};
+use log::debug;
 use nom::character::complete::u32;
 use nom::character::one_of;
 use nom::combinator::map;
@@ -28,16 +32,14 @@ struct Day1 {
     position: u32,
     zeros: u32,
     count_all_zeros: bool,
-    verbose: bool,
 }
 
 impl Day1 {
-    fn new(gold: bool, verbose: bool) -> Self {
+    fn new(gold: bool) -> Self {
         Self {
             position: 50,
             zeros: 0,
             count_all_zeros: gold,
-            verbose,
         }
     }
 
@@ -96,32 +98,24 @@ impl LineStreamHandler for Day1 {
         )?;
         let old_position = self.position;
         self.rotate(direction, amount);
-        if self.verbose {
-            println!(
-                "[-] {}{:>3}: {:>2} -> {:>2} ({:>5})",
-                direction, amount, old_position, self.position, self.zeros
-            );
-        }
+        debug!(
+            "{}{:>3}: {:>2} -> {:>2} ({:>5})",
+            direction, amount, old_position, self.position, self.zeros
+        );
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        if !self.verbose {
-            println!("[-] Final position: {}", self.position);
-        }
-        println!(
-            "[{}] Password:       {}",
-            if self.count_all_zeros {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.zeros
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_part(self.count_all_zeros, "Password", self.zeros)
+            .with_extra("Final position", self.position))
     }
 }
 
-pub fn new(gold: bool, verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(1, "Secret Entrance", Day1::new(gold, verbose)))
+pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(Day1::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 1, "Secret Entrance", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
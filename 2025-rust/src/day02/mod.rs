@@ -1,7 +1,8 @@
 use std::{collections::HashSet, error::Error};
 
 use aoc_common_rs::{
-    day::{Day, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{LineStreamHandler, parse_full_string},
 };
 use nom::{
@@ -16,7 +17,7 @@ fn upper(num: u64, parts: u32) -> (u64, bool) {
     }
 
     let log = num.ilog10();
-    if ((log + 1) % parts) != 0 {
+    if !(log + 1).is_multiple_of(parts) {
         return (10u64.pow(log / parts), false);
     }
 
@@ -90,17 +91,21 @@ impl LineStreamHandler for Day2 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!("[-] Number of invalid IDs: {}", self.invalids.len());
-        println!(
-            "[{}] Sum of invalid IDs:    {}",
-            SILVER_ANSI,
-            self.invalids.into_iter().sum::<u64>()
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_extra("Number of invalid IDs", self.invalids.len())
+            .with_part(
+                self.gold,
+                "Sum of invalid IDs",
+                self.invalids.into_iter().sum::<u64>(),
+            ))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(2, "Gift Shop", Day2::new(gold)))
+    Ok(Day::new(Day2::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 2, "Gift Shop", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -1,7 +1,8 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
     some_or_continue,
 };
@@ -30,7 +31,8 @@ impl PackedBuffer {
         const LSB_MASK: u64 = 0x84210842108421;
         let insert = (digit as u64) << self.last_offset;
         let shifted = (self.value >> 5) | insert | (MSB_MASK & self.mask);
-        let mut position = ((shifted - self.value - LSB_MASK) & MSB_MASK).trailing_zeros();
+        let mut position =
+            (shifted.wrapping_sub(self.value).wrapping_sub(LSB_MASK) & MSB_MASK).trailing_zeros();
         position -= position % 5;
         let fixed_mask = (1 << position) - 1;
         if fixed_mask >= self.mask {
@@ -77,20 +79,19 @@ impl LineStreamHandler for Day3 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Total output joltage: {}",
-            if self.batteries_per_bank > 10 {
-                GOLD_ANSI
-            } else {
-                SILVER_ANSI
-            },
-            self.total
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(
+            self.batteries_per_bank > 10,
+            "Total output joltage",
+            self.total,
+        ))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(3, "Lobby", Day3::new(gold)))
+    Ok(Day::new(Day3::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 3, "Lobby", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
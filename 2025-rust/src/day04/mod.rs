@@ -1,12 +1,16 @@
 use std::{
     error::Error,
     fmt::{Display, Write},
-    u8,
+    path::PathBuf,
 };
 
+use log::debug;
+
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::LineStreamHandler,
+    answers::Answers,
+    day::{Day, Param, Parts, Puzzle},
+    error::AocError,
+    frames::{FrameRecorder, Rgb},
     terrain::Terrain,
 };
 
@@ -16,9 +20,9 @@ enum Cell {
     Roll { neighbors: u8 },
 }
 
-impl Display for Cell {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_char(match self {
+impl Cell {
+    fn glyph(&self) -> char {
+        match self {
             Cell::Free => '.',
             Cell::Roll { neighbors } => {
                 if *neighbors < 4 {
@@ -27,20 +31,51 @@ impl Display for Cell {
                     '@'
                 }
             }
-        })
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Cell::Free => Rgb(32, 32, 32),
+            Cell::Roll { neighbors } if *neighbors < 4 => Rgb(255, 140, 0),
+            Cell::Roll { .. } => Rgb(200, 200, 200),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.glyph())
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Cell::Free),
+            '@' => Ok(Cell::Roll { neighbors: u8::MAX }),
+            _ => Err(AocError::invalid_input("unrecognized symbol")),
+        }
     }
 }
 
 struct Day4 {
     wall: Terrain<Cell>,
-    verbose: bool,
+    recording: Option<(PathBuf, FrameRecorder<Cell>)>,
 }
 
 impl Day4 {
-    fn new(verbose: bool) -> Self {
+    fn new(wall: Terrain<Cell>, record: Option<PathBuf>) -> Self {
         Self {
-            wall: Terrain::new(),
-            verbose,
+            wall,
+            recording: record.map(|path| {
+                (
+                    path,
+                    FrameRecorder::new(Cell::color).with_glyph(Cell::glyph),
+                )
+            }),
         }
     }
 
@@ -50,55 +85,36 @@ impl Day4 {
                 continue;
             }
 
-            let mut neighbors = 0u8;
-            for (dir, neighbor) in self.wall.neighbors(pt) {
-                if matches!(self.wall[neighbor], Cell::Roll { neighbors: _ }) {
-                    neighbors += 1;
-                }
-                if let Some(diagonal) = self.wall.neighbor(neighbor, dir.clockwise()) {
-                    if matches!(self.wall[diagonal], Cell::Roll { neighbors: _ }) {
-                        neighbors += 1;
-                    }
-                }
-            }
+            let neighbors = self
+                .wall
+                .neighbors8(pt)
+                .filter(|(_, neighbor)| matches!(self.wall[*neighbor], Cell::Roll { neighbors: _ }))
+                .count() as u8;
 
             self.wall[pt] = Cell::Roll { neighbors };
         }
+        if let Some((_, recorder)) = &mut self.recording {
+            recorder.record(&self.wall);
+        }
     }
 
     fn remove_rolls(&mut self) -> usize {
         let mut rolls = 0;
         for pt in self.wall.points() {
-            if let Cell::Roll { neighbors } = self.wall[pt] {
-                if neighbors < 4 {
-                    self.wall[pt] = Cell::Free;
-                    rolls += 1;
-                }
+            if let Cell::Roll { neighbors } = self.wall[pt]
+                && neighbors < 4
+            {
+                self.wall[pt] = Cell::Free;
+                rolls += 1;
             }
         }
 
         rolls
     }
-}
-
-impl LineStreamHandler for Day4 {
-    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
-        let mut row = self.wall.new_row();
-        for ch in line.chars() {
-            row.push(match ch {
-                '.' => Cell::Free,
-                '@' => Cell::Roll { neighbors: u8::MAX },
-                _ => panic!("Unrecognized symbol {}", ch),
-            });
-        }
-        self.wall.push_row(row);
-        Ok(())
-    }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self) -> Result<Answers, Box<dyn Error>> {
         self.calculate_neighbors();
         let rolls = self.remove_rolls();
-        println!("[{}] Accessible rolls:      {}", SILVER_ANSI, rolls);
         let mut rolls_this_round = rolls;
         let mut total_rolls = rolls;
         while rolls_this_round > 0 {
@@ -106,14 +122,23 @@ impl LineStreamHandler for Day4 {
             rolls_this_round = self.remove_rolls();
             total_rolls += rolls_this_round;
         }
-        println!("[{}] Total removable rolls: {}", GOLD_ANSI, total_rolls);
-        if self.verbose {
-            println!("{}", self.wall);
+        debug!("Remaining rolls:\n{}", self.wall);
+        if let Some((path, recorder)) = &self.recording {
+            recorder.save(path)?;
         }
-        Ok(())
+        Ok(Answers::new()
+            .with_silver("Accessible rolls", rolls)
+            .with_gold("Total removable rolls", total_rolls))
     }
 }
 
-pub fn new(verbose: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(4, "Printing Department", Day4::new(verbose)))
+pub fn new(record: Option<PathBuf>) -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(
+        Terrain::builder().into_handler(|wall, _| Day4::new(wall, record).finish()),
+    ))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 4, "Printing Department", Parts::Both, |args| {
+    new(args.value(Param::RECORD.name)?)
+})
+.with_params(&[Param::RECORD]);
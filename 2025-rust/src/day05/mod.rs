@@ -1,8 +1,9 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
-    line_stream::{LineStreamHandlerOnce, parse_full_string},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    line_stream::{SectionedHandler, parse_full_string},
     multi_range::MultiRangeInclusive,
 };
 use nom::{
@@ -22,58 +23,34 @@ impl Day5 {
             fresh_avail: 0,
         }
     }
-}
-
-struct Day5Fresh(Day5);
-
-impl LineStreamHandlerOnce for Day5Fresh {
-    fn update(
-        mut self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
-        if line.is_empty() {
-            return Ok(Box::new(Day5Available(self.0)));
-        }
 
+    fn add_fresh(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         let (from, to) = parse_full_string(line, separated_pair(u64, char('-'), u64))?;
-        self.0.fresh_ids.insert(from..=to);
-        Ok(self)
+        self.fresh_ids.insert(from..=to);
+        Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        unreachable!()
-    }
-}
-
-struct Day5Available(Day5);
-
-impl LineStreamHandlerOnce for Day5Available {
-    fn update(
-        mut self: Box<Self>,
-        line: &str,
-    ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>> {
+    fn add_available(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         let id = parse_full_string(line, u64)?;
-        if self.0.fresh_ids.contains(&id) {
-            self.0.fresh_avail += 1;
+        if self.fresh_ids.contains(&id) {
+            self.fresh_avail += 1;
         }
-
-        Ok(self)
+        Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Fresh available ingredients: {}",
-            SILVER_ANSI, self.0.fresh_avail
-        );
-        println!(
-            "[{}] Total fresh ingredients:     {}",
-            GOLD_ANSI,
-            self.0.fresh_ids.count()
-        );
-        Ok(())
+    fn finish(self) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Fresh available ingredients", self.fresh_avail)
+            .with_gold("Total fresh ingredients", self.fresh_ids.count()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new_once(5, "Cafeteria", Day5Fresh(Day5::new())))
+    Ok(Day::new(
+        SectionedHandler::new(Day5::new(), Day5::finish)
+            .with_section(Day5::add_fresh)
+            .with_section(Day5::add_available),
+    ))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 5, "Cafeteria", Parts::Both, |_| new());
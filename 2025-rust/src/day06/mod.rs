@@ -4,7 +4,9 @@ use std::{
 };
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{LineStreamHandler, parse_full_string},
 };
 use itertools::Itertools;
@@ -61,12 +63,14 @@ fn parse_aggregates(line: &str) -> Result<Vec<Aggregate>, nom::error::Error<usiz
 
 struct Day6Silver {
     columns: Vec<Vec<u64>>,
+    sum: Option<u64>,
 }
 
 impl Day6Silver {
     fn new() -> Self {
         Self {
             columns: Vec::new(),
+            sum: None,
         }
     }
 }
@@ -74,14 +78,12 @@ impl Day6Silver {
 impl LineStreamHandler for Day6Silver {
     fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         if let Ok(aggregates) = parse_aggregates(line) {
-            println!(
-                "[{}] Sum of results: {}",
-                SILVER_ANSI,
+            self.sum = Some(
                 self.columns
                     .iter()
-                    .zip(aggregates.into_iter())
+                    .zip(aggregates)
                     .map(|(column, op)| op.execute::<u64, _>(column.iter()))
-                    .sum::<u64>()
+                    .sum::<u64>(),
             );
             return Ok(());
         }
@@ -99,19 +101,24 @@ impl LineStreamHandler for Day6Silver {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let sum = self
+            .sum
+            .ok_or_else(|| AocError::invalid_input("operator line not found"))?;
+        Ok(Answers::new().with_silver("Sum of results", sum))
     }
 }
 
 struct Day6Gold {
     columns: Vec<Vec<u8>>,
+    sum: Option<u64>,
 }
 
 impl Day6Gold {
     fn new() -> Self {
         Self {
             columns: Vec::new(),
+            sum: None,
         }
     }
 }
@@ -136,13 +143,11 @@ impl LineStreamHandler for Day6Gold {
                     })
                     .collect_vec()
                 });
-            println!(
-                "[{}] Sum of results: {}",
-                GOLD_ANSI,
+            self.sum = Some(
                 columns
-                    .zip(aggregates.into_iter())
+                    .zip(aggregates)
                     .map(|(column, op)| op.execute::<u64, _>(column.iter()))
-                    .sum::<u64>()
+                    .sum::<u64>(),
             );
             return Ok(());
         }
@@ -159,15 +164,22 @@ impl LineStreamHandler for Day6Gold {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let sum = self
+            .sum
+            .ok_or_else(|| AocError::invalid_input("operator line not found"))?;
+        Ok(Answers::new().with_gold("Sum of results", sum))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
     Ok(if gold {
-        Day::new(6, "Trash Compactor", Day6Gold::new())
+        Day::new(Day6Gold::new())
     } else {
-        Day::new(6, "Trash Compactor", Day6Silver::new())
+        Day::new(Day6Silver::new())
     })
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 6, "Trash Compactor", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
@@ -1,7 +1,8 @@
-use std::{error::Error, usize};
+use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::LineStreamHandler,
 };
 
@@ -45,17 +46,15 @@ impl LineStreamHandler for Day7 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!("[{}] Splits: {}", SILVER_ANSI, self.splits);
-        println!(
-            "[{}] Paths:  {}",
-            GOLD_ANSI,
-            self.beams.into_iter().sum::<usize>()
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new()
+            .with_silver("Splits", self.splits)
+            .with_gold("Paths", self.beams.into_iter().sum::<usize>()))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(7, "Laboratories", Day7::new()))
+    Ok(Day::new(Day7::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 7, "Laboratories", Parts::Both, |_| new());
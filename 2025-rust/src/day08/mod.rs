@@ -1,7 +1,9 @@
 use std::{collections::HashMap, error::Error, num::NonZeroUsize};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{LineStreamHandler, parse_full_string},
     point::Point3,
 };
@@ -18,7 +20,7 @@ fn euclidean_distance(pt1: &Point3<f32>, pt2: &Point3<f32>) -> f32 {
     (dx * dx + dy * dy + dz * dz).sqrt()
 }
 
-fn replace_all<T: Copy + PartialEq>(vec: &mut Vec<T>, search: T, replacement: T) {
+fn replace_all<T: Copy + PartialEq>(vec: &mut [T], search: T, replacement: T) {
     for item in vec.iter_mut() {
         if *item == search {
             *item = replacement;
@@ -124,7 +126,7 @@ impl LineStreamHandler for Day8 {
         Ok(())
     }
 
-    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(mut self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.circuit_ids.resize(self.junction_boxes.len(), None);
         self.unconnected = self.junction_boxes.len();
         let mut pairs = self.ordered_pairs();
@@ -132,34 +134,35 @@ impl LineStreamHandler for Day8 {
             for pair in pairs {
                 self.connect(pair.0, pair.1);
                 if self.unconnected == 0 && self.circuits == 1 {
-                    println!(
-                        "[{}] Product of last Xs: {}",
-                        GOLD_ANSI,
-                        self.junction_boxes[pair.0].0 as u64 * self.junction_boxes[pair.1].0 as u64
-                    );
-                    break;
+                    return Ok(Answers::new().with_gold(
+                        "Product of last Xs",
+                        self.junction_boxes[pair.0].0 as u64 * self.junction_boxes[pair.1].0 as u64,
+                    ));
                 }
             }
+            Err(AocError::unsolvable("junction boxes cannot all be connected").into())
         } else {
             pairs.truncate(10usize.pow(self.junction_boxes.len().ilog10()));
             for pair in pairs {
                 self.connect(pair.0, pair.1);
             }
             let (circuit_sizes, _) = self.get_circuit_sizes();
-            println!(
-                "[{}] Product of sizes of three largest circuits: {}",
-                SILVER_ANSI,
+            Ok(Answers::new().with_silver(
+                "Product of sizes of three largest circuits",
                 circuit_sizes
                     .into_iter()
                     .take(3)
                     .map(|(_, size)| size)
-                    .product::<usize>()
-            );
+                    .product::<usize>(),
+            ))
         }
-        Ok(())
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(8, "Playground", Day8::new(gold)))
+    Ok(Day::new(Day8::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 8, "Playground", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
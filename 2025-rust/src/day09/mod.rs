@@ -1,7 +1,8 @@
 use std::{collections::BTreeMap, error::Error};
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{LineStreamHandler, parse_full_string},
     multi_range::MultiRangeInclusive,
     point::{Direction2, Point2},
@@ -173,12 +174,11 @@ impl LineStreamHandler for Day9 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         if self.gold {
             let interior = self.interior();
-            println!(
-                "[{}] Maximum area: {}",
-                GOLD_ANSI,
+            Ok(Answers::new().with_gold(
+                "Maximum area",
                 self.tiles
                     .iter()
                     .cartesian_product(self.tiles.iter())
@@ -201,29 +201,32 @@ impl LineStreamHandler for Day9 {
                         Some((tile1.0.abs_diff(tile2.0) + 1) * (tile1.1.abs_diff(tile2.1) + 1))
                     })
                     .max()
-                    .unwrap_or(0)
-            );
+                    .unwrap_or(0),
+            ))
         } else {
-            println!(
-                "[{}] Maximum area: {}",
-                SILVER_ANSI,
+            Ok(Answers::new().with_silver(
+                "Maximum area",
                 self.tiles
                     .iter()
                     .cartesian_product(self.tiles.iter())
-                    .filter_map(|(tile1, tile2)| if tile1.1 > tile2.1 {
-                        None
-                    } else {
-                        Some((tile1.0.abs_diff(tile2.0) + 1) * (tile1.1.abs_diff(tile2.1) + 1))
+                    .filter_map(|(tile1, tile2)| {
+                        if tile1.1 > tile2.1 {
+                            None
+                        } else {
+                            Some((tile1.0.abs_diff(tile2.0) + 1) * (tile1.1.abs_diff(tile2.1) + 1))
+                        }
                     })
                     .max()
-                    .unwrap_or(0)
-            );
+                    .unwrap_or(0),
+            ))
         }
-
-        Ok(())
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(9, "Movie Theater", Day9::new(gold)))
+    Ok(Day::new(Day9::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 9, "Movie Theater", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
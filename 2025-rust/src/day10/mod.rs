@@ -1,16 +1,19 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    error::AocError,
     line_stream::{LineStreamHandler, parse_full_string},
+    parse::bitmask,
 };
 #[cfg(feature = "z3")]
 use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
-    character::complete::{char, one_of, u32, usize},
+    character::complete::{char, u32, usize},
     combinator::map,
-    multi::{fold_many0, separated_list1},
+    multi::separated_list1,
     sequence::{preceded, separated_pair, terminated},
 };
 #[cfg(feature = "z3")]
@@ -64,7 +67,7 @@ impl Machine {
     }
 
     #[cfg(feature = "z3")]
-    fn min_presses_gold(&self) -> u32 {
+    fn min_presses_gold(&self) -> Result<u32, AocError> {
         let buttons = (0..self.button_lights.len())
             .map(|_| Int::fresh_const("button"))
             .collect_vec();
@@ -96,12 +99,12 @@ impl Machine {
                     .sum::<u32>(),
             );
         }
-        min_presses
+        Ok(min_presses)
     }
 
     #[cfg(not(feature = "z3"))]
-    fn min_presses_gold(&self) -> u32 {
-        unimplemented!("This requires the \"z3\" feature.")
+    fn min_presses_gold(&self) -> Result<u32, AocError> {
+        Err(AocError::MissingFeature("z3"))
     }
 }
 
@@ -113,23 +116,7 @@ fn parse_machine(line: &str) -> Result<Machine, nom::error::Error<usize>> {
                 char('['),
                 terminated(
                     separated_pair(
-                        map(
-                            fold_many0(
-                                one_of(".#"),
-                                || (0usize, 0usize),
-                                |(acc, len), ch| {
-                                    (
-                                        match ch {
-                                            '.' => acc,
-                                            '#' => acc | (1 << len),
-                                            _ => unreachable!(),
-                                        },
-                                        len + 1,
-                                    )
-                                },
-                            ),
-                            |(result, _)| result,
-                        ),
+                        map(bitmask('#', '.'), |(lights, _)| lights),
                         tag("] ("),
                         separated_pair(
                             separated_list1(
@@ -168,23 +155,22 @@ impl LineStreamHandler for Day10 {
     fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
         let machine = parse_machine(line)?;
         self.min_presses += if self.gold {
-            machine.min_presses_gold()
+            machine.min_presses_gold()?
         } else {
             machine.min_presses_silver()
         };
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Minimum button presses: {}",
-            if self.gold { GOLD_ANSI } else { SILVER_ANSI },
-            self.min_presses
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers::new().with_part(self.gold, "Minimum button presses", self.min_presses))
     }
 }
 
 pub fn new(gold: bool) -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(10, "Factory", Day10::new(gold)))
+    Ok(Day::new(Day10::new(gold)))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 10, "Factory", Parts::Selectable, |args| {
+    new(args.flag("gold"))
+});
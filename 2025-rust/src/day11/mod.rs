@@ -4,8 +4,9 @@ use std::{
 };
 
 use aoc_common_rs::{
+    answers::Answers,
     cc::ThreeCC,
-    day::{Day, GOLD_ANSI, SILVER_ANSI},
+    day::{Day, Parts, Puzzle},
     line_stream::{LineStreamHandler, parse_full_string, take_fixed},
     some_or_break,
 };
@@ -35,7 +36,7 @@ impl Day11 {
     }
 
     fn get_device_index(&self, device: ThreeCC) -> Option<usize> {
-        self.devices.get(&device).map(|index| *index)
+        self.devices.get(&device).copied()
     }
 
     fn get_or_create_device_index(&mut self, device: ThreeCC) -> usize {
@@ -103,11 +104,10 @@ impl LineStreamHandler for Day11 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[{}] Paths from you to out: {}",
-            SILVER_ANSI,
-            self.paths_from_to(YOU_3CC, OUT_3CC)
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let mut answers = Answers::new().with_silver(
+            "Paths from you to out",
+            self.paths_from_to(YOU_3CC, OUT_3CC),
         );
         let fft_to_dac = self.paths_from_to(FFT_3CC, DAC_3CC);
         let dac_to_fft = self.paths_from_to(DAC_3CC, FFT_3CC);
@@ -117,30 +117,26 @@ impl LineStreamHandler for Day11 {
             }
             let srv_to_fft = self.paths_from_to(SVR_3CC, FFT_3CC);
             let dac_to_out = self.paths_from_to(DAC_3CC, OUT_3CC);
-            println!("[-] Paths from srv to fft: {}", srv_to_fft);
-            println!("[-] Paths from fft to dac: {}", fft_to_dac);
-            println!("[-] Paths from dac to out: {}", dac_to_out);
-            println!(
-                "[{}] Full paths:            {}",
-                GOLD_ANSI,
-                srv_to_fft * fft_to_dac * dac_to_out
-            );
+            answers = answers
+                .with_gold("Full paths", srv_to_fft * fft_to_dac * dac_to_out)
+                .with_extra("Paths from srv to fft", srv_to_fft)
+                .with_extra("Paths from fft to dac", fft_to_dac)
+                .with_extra("Paths from dac to out", dac_to_out);
         } else if dac_to_fft > 0 {
             let srv_to_dac = self.paths_from_to(SVR_3CC, DAC_3CC);
             let fft_to_out = self.paths_from_to(FFT_3CC, OUT_3CC);
-            println!("[-] Paths from srv to dac: {}", srv_to_dac);
-            println!("[-] Paths from dac to fft: {}", dac_to_fft);
-            println!("[-] Paths from fft to out: {}", fft_to_out);
-            println!(
-                "[{}] Full paths:            {}",
-                GOLD_ANSI,
-                srv_to_dac * dac_to_fft * fft_to_out
-            );
+            answers = answers
+                .with_gold("Full paths", srv_to_dac * dac_to_fft * fft_to_out)
+                .with_extra("Paths from srv to dac", srv_to_dac)
+                .with_extra("Paths from dac to fft", dac_to_fft)
+                .with_extra("Paths from fft to out", fft_to_out);
         }
-        Ok(())
+        Ok(answers)
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(11, "Reactor", Day11::new()))
+    Ok(Day::new(Day11::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 11, "Reactor", Parts::Both, |_| new());
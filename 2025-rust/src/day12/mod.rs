@@ -1,13 +1,15 @@
 use std::error::Error;
 
 use aoc_common_rs::{
-    day::Day,
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
     line_stream::{LineStreamHandler, parse_full_string},
+    parse::bitmask,
 };
 use nom::{
     bytes::complete::tag,
-    character::complete::{char, one_of, usize},
-    multi::{fold_many1, separated_list1},
+    character::complete::{char, usize},
+    multi::separated_list1,
     sequence::{separated_pair, terminated},
 };
 
@@ -60,27 +62,11 @@ impl LineStreamHandler for Day12 {
             return Ok(());
         }
 
-        if let Ok(_) = parse_full_string(line, terminated(usize, char(':'))) {
+        if parse_full_string(line, terminated(usize, char(':'))).is_ok() {
             return Ok(());
         }
 
-        if let Ok((shape, len)) = parse_full_string(
-            line,
-            fold_many1(
-                one_of(".#"),
-                || (0usize, 0usize),
-                |(shape, len), ch| {
-                    (
-                        match ch {
-                            '#' => (shape << 1) | 1,
-                            '.' => shape << 1,
-                            _ => unreachable!(),
-                        },
-                        len + 1,
-                    )
-                },
-            ),
-        ) {
+        if let Ok((shape, len)) = parse_full_string(line, bitmask::<usize>('#', '.')) {
             self.current_shape |= shape << self.current_shape_len;
             self.current_shape_len += len;
             return Ok(());
@@ -109,19 +95,27 @@ impl LineStreamHandler for Day12 {
         Ok(())
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
-        println!(
-            "[-] Lower bound of fitting regions: {}",
-            self.fitting_regions_lbound
-        );
-        println!(
-            "[-] Upper bound of fitting regions: {}",
-            self.fitting_regions_ubound
-        );
-        Ok(())
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let mut answers = Answers::new();
+        if self.fitting_regions_lbound == self.fitting_regions_ubound {
+            answers = answers.with_silver("Fitting regions", self.fitting_regions_lbound);
+        }
+        Ok(answers
+            .with_extra(
+                "Lower bound of fitting regions",
+                self.fitting_regions_lbound,
+            )
+            .with_extra(
+                "Upper bound of fitting regions",
+                self.fitting_regions_ubound,
+            ))
     }
 }
 
 pub fn new() -> Result<Day, Box<dyn Error>> {
-    Ok(Day::new(12, "Christmas Tree Farm", Day12::new()))
+    Ok(Day::new(Day12::new()))
 }
+
+pub const PUZZLE: Puzzle = Puzzle::new(2025, 12, "Christmas Tree Farm", Parts::SilverOnly, |_| {
+    new()
+});
@@ -0,0 +1,270 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use aoc_common_rs::{
+    day::{Args, Param, ParamKind, Parts, Puzzle, Registry},
+    input::Input,
+    report::Format,
+};
+
+use clap::{
+    builder::PossibleValuesParser, value_parser, Arg, ArgAction, ArgGroup, ArgMatches, Command,
+};
+
+pub fn command(registry: &Registry) -> Command {
+    let mut command = Command::new("aoc")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Advent of Code solutions")
+        .after_help(
+            "Exit codes: 1 failure, 2 usage error, 3 parse error, 4 invalid input, \
+             5 unsolvable, 6 missing feature\n\n\
+             AOC_LOG adds comma-separated log filters by day or module, \
+             e.g. AOC_LOG=2022/09=trace\n\n\
+             fetch and submit read the session token from AOC_SESSION, \
+             or else from .aoc-session in the data directory",
+        )
+        .subcommand_required(true)
+        .arg(
+            Arg::new("timed")
+                .short('t')
+                .long("timed")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("mem")
+                .long("mem")
+                .action(ArgAction::SetTrue)
+                .global(true)
+                .help("Report the heap used while reading the input and while finishing"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .action(ArgAction::Count)
+                .global(true)
+                .help("Log more diagnostics (-v info, -vv debug, -vvv trace)"),
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("verbose")
+                .global(true)
+                .help("Don't log anything, not even warnings"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(PossibleValuesParser::new(Format::NAMES))
+                .default_value("auto")
+                .global(true),
+        )
+        .arg(
+            Arg::new("data-dir")
+                .long("data-dir")
+                .value_parser(value_parser!(PathBuf))
+                .default_value(".")
+                .global(true),
+        )
+        .subcommand(Command::new("list").about("List every registered puzzle"))
+        .subcommand(
+            Command::new("verify")
+                .about("Check the answers of the inputs in the data directories")
+                .arg(Arg::new("year").value_parser(value_parser!(u16)))
+                .arg(Arg::new("day").value_parser(value_parser!(u8))),
+        )
+        .subcommand(
+            Command::new("new-day")
+                .about("Create the module of a day and its sample files from a template")
+                .args(day_args())
+                .arg(
+                    Arg::new("title")
+                        .long("title")
+                        .help("The title of the puzzle [default: Day N]"),
+                ),
+        )
+        .subcommand(
+            Command::new("fetch")
+                .about("Download the input of a day to YYYY-data/dayNN-input")
+                .args(day_args())
+                .arg(
+                    Arg::new("force")
+                        .long("force")
+                        .action(ArgAction::SetTrue)
+                        .help("Download the input even if it's already there"),
+                ),
+        )
+        .subcommand(
+            Command::new("submit")
+                .about("Submit the answer to a part, logged to YYYY-data/dayNN.submissions")
+                .args(day_args())
+                .arg(
+                    Arg::new("part")
+                        .required(true)
+                        .value_parser(PossibleValuesParser::new(["silver", "gold"])),
+                )
+                .arg(
+                    Arg::new("answer")
+                        .help("The answer to submit [default: the one solved from the input]"),
+                ),
+        );
+    for year in registry.years() {
+        command = command.subcommand(
+            Command::new(year.to_string())
+                .about(format!("Advent of Code {}", year))
+                .arg_required_else_help(true)
+                .args_conflicts_with_subcommands(true)
+                .arg(
+                    Arg::new("all")
+                        .long("all")
+                        .action(ArgAction::SetTrue)
+                        .help("Run every day on its discovered input"),
+                )
+                .arg(
+                    Arg::new("sample")
+                        .long("sample")
+                        .action(ArgAction::SetTrue)
+                        .requires("all")
+                        .help("Run every day on its first sample instead"),
+                )
+                .arg(
+                    Arg::new("jobs")
+                        .short('j')
+                        .long("jobs")
+                        .value_parser(value_parser!(usize))
+                        .requires("all")
+                        .help("Number of days run in parallel [default: number of CPUs]"),
+                )
+                .subcommands(registry.year(year).map(puzzle_command)),
+        );
+    }
+    command
+}
+
+fn day_args() -> [Arg; 2] {
+    [
+        Arg::new("year")
+            .required(true)
+            .value_parser(value_parser!(u16).range(2015..)),
+        Arg::new("day")
+            .required(true)
+            .value_parser(value_parser!(u8).range(1..=25)),
+    ]
+}
+
+fn puzzle_command(puzzle: &Puzzle) -> Command {
+    let mut command = Command::new(puzzle.number.to_string())
+        .visible_alias(format!("day{:02}", puzzle.number))
+        .about(puzzle.title)
+        .arg(
+            Arg::new("input")
+                .short('i')
+                .long("input")
+                .value_parser(value_parser!(PathBuf))
+                .help("Read the input from a file, - for stdin [default: YYYY-data/dayNN-input]"),
+        )
+        .arg(
+            Arg::new("sample")
+                .long("sample")
+                .num_args(0..=1)
+                .default_missing_value("")
+                .conflicts_with("input")
+                .help("Read YYYY-data/dayNN-sample[SAMPLE]"),
+        )
+        .args(bench_args())
+        .args(puzzle.all_params().map(param_arg));
+    if matches!(puzzle.parts, Parts::Selectable) {
+        command = command.group(ArgGroup::new("part").args(["silver", "gold"]));
+    }
+    if puzzle.number < 10 {
+        command.alias(format!("{:02}", puzzle.number))
+    } else {
+        command
+    }
+}
+
+fn bench_args() -> [Arg; 5] {
+    [
+        Arg::new("bench")
+            .long("bench")
+            .value_name("RUNS")
+            .value_parser(value_parser!(usize))
+            .num_args(0..=1)
+            .default_missing_value("100")
+            .help("Run the day repeatedly over the buffered input"),
+        Arg::new("warmup")
+            .long("warmup")
+            .value_name("RUNS")
+            .value_parser(value_parser!(usize))
+            .default_value("3")
+            .requires("bench"),
+        Arg::new("baseline")
+            .long("baseline")
+            .value_name("PATH")
+            .value_parser(value_parser!(PathBuf))
+            .requires("bench")
+            .help("Compare with a baseline saved by --save-baseline"),
+        Arg::new("save-baseline")
+            .long("save-baseline")
+            .value_name("PATH")
+            .value_parser(value_parser!(PathBuf))
+            .requires("bench"),
+        Arg::new("tolerance")
+            .long("tolerance")
+            .value_name("PERCENT")
+            .value_parser(value_parser!(f64))
+            .default_value("10")
+            .requires("bench")
+            .help("Slowdown of the median flagged as a regression"),
+    ]
+    .map(|arg| arg.help_heading("Benchmark"))
+}
+
+fn param_arg(param: &Param) -> Arg {
+    let arg = Arg::new(param.name).long(param.name).short(param.short);
+    match param.kind {
+        ParamKind::Flag => arg.action(ArgAction::SetTrue),
+        ParamKind::Count => arg.action(ArgAction::Count),
+        ParamKind::Value { required } => arg.action(ArgAction::Set).required(required),
+    }
+}
+
+pub fn input(
+    data_dir: &Path,
+    puzzle: &Puzzle,
+    matches: &ArgMatches,
+) -> Result<Input, Box<dyn Error>> {
+    if let Some(name) = matches.get_one::<String>("sample") {
+        Input::sample(data_dir, puzzle, name)
+    } else {
+        match matches.get_one::<PathBuf>("input") {
+            Some(path) if path.as_os_str() == "-" => Ok(Input::Stdin),
+            Some(path) => Ok(Input::File(path.clone())),
+            None => Ok(Input::discover(data_dir, puzzle)),
+        }
+    }
+}
+
+pub fn args(puzzle: &Puzzle, input: &Input, matches: &ArgMatches) -> Args {
+    let mut args = Args::new();
+    if input.is_sample() {
+        args.set_count("sample", 1);
+    }
+    for param in puzzle.all_params() {
+        match param.kind {
+            ParamKind::Flag => args.set_count(param.name, matches.get_flag(param.name).into()),
+            ParamKind::Count => args.set_count(param.name, matches.get_count(param.name)),
+            ParamKind::Value { .. } => {
+                if let Some(value) = matches.get_one::<String>(param.name) {
+                    args.set_value(param.name, value.clone());
+                }
+            }
+        }
+    }
+    args
+}
@@ -0,0 +1,672 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common_rs::verify::{answers_entry, data_dir, sidecar_path, Expected};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!("aoc-cli-rs/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The requests made to the Advent of Code server, `cookie` being the value
+/// of the `Cookie` header.
+pub trait Http {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, Box<dyn Error>>;
+    fn post(
+        &self,
+        url: &str,
+        cookie: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error>>;
+}
+
+pub struct UreqHttp(ureq::Agent);
+
+impl UreqHttp {
+    pub fn new() -> Self {
+        Self(ureq::AgentBuilder::new().user_agent(USER_AGENT).build())
+    }
+
+    fn response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, Box<dyn Error>> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        })
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, Box<dyn Error>> {
+        Self::response(self.0.get(url).set("Cookie", cookie).call())
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        cookie: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error>> {
+        Self::response(self.0.post(url).set("Cookie", cookie).send_form(form))
+    }
+}
+
+/// Reads the session token from `AOC_SESSION`, or else from `.aoc-session` in
+/// the data directory.
+pub fn session(root: &Path) -> Result<String, Box<dyn Error>> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_owned());
+    }
+    let path = root.join(".aoc-session");
+    match fs::read_to_string(&path) {
+        Ok(session) => Ok(session.trim().to_owned()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(format!(
+            "no session token, set AOC_SESSION or write it to {}",
+            path.display()
+        )
+        .into()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answered too recently, nothing was checked.
+    TooRecent,
+    /// The part is locked or already solved.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    const LABELS: [(Self, &'static str); 7] = [
+        (Self::Correct, "correct"),
+        (Self::TooHigh, "too-high"),
+        (Self::TooLow, "too-low"),
+        (Self::Wrong, "wrong"),
+        (Self::TooRecent, "too-recent"),
+        (Self::WrongLevel, "wrong-level"),
+        (Self::Unknown, "unknown"),
+    ];
+
+    pub fn label(self) -> &'static str {
+        Self::LABELS
+            .iter()
+            .find(|(verdict, _)| *verdict == self)
+            .unwrap()
+            .1
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        Self::LABELS
+            .iter()
+            .find(|(_, l)| *l == label)
+            .map(|(verdict, _)| *verdict)
+    }
+
+    /// Reads the verdict from the page returned for a submission.
+    pub fn from_page(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Self::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Self::TooHigh
+            } else if page.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Self::TooRecent
+        } else if page.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+
+    fn is_rejection(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            Self::Correct => "that's the right answer",
+            Self::TooHigh => "that's not the right answer, it's too high",
+            Self::TooLow => "that's not the right answer, it's too low",
+            Self::Wrong => "that's not the right answer",
+            Self::TooRecent => "an answer was given too recently, try again later",
+            Self::WrongLevel => "that part is locked or already solved",
+            Self::Unknown => "the server's response wasn't understood",
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Submission {
+    pub time: u64,
+    pub gold: bool,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// The answers submitted for a day, logged to `YYYY-data/dayNN.submissions`
+/// one tab-separated `time part answer verdict` line at a time.
+#[derive(Clone, Default, Debug)]
+pub struct Submissions(pub Vec<Submission>);
+
+impl Submissions {
+    pub fn path(root: &Path, year: u16, day: u8) -> PathBuf {
+        data_dir(root, year).join(format!("day{:02}.submissions", day))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err.into()),
+        };
+        let mut submissions = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let submission = match fields[..] {
+                [time, part, answer, verdict] => Some(Submission {
+                    time: time.parse()?,
+                    gold: part == "gold",
+                    answer: answer.to_owned(),
+                    verdict: Verdict::from_label(verdict)
+                        .ok_or_else(|| format!("unknown verdict {}", verdict))?,
+                }),
+                _ => None,
+            };
+            submissions
+                .push(submission.ok_or_else(|| {
+                    format!("{}:{}: malformed submission", path.display(), i + 1)
+                })?);
+        }
+        Ok(Self(submissions))
+    }
+
+    fn append(path: &Path, submission: &Submission) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            submission.time,
+            if submission.gold { "gold" } else { "silver" },
+            submission.answer,
+            submission.verdict.label()
+        )
+    }
+
+    /// Explains why submitting `answer` would be pointless, if it would be:
+    /// the part is already solved, or the answer or a bound it's past was
+    /// already rejected.
+    pub fn check(&self, gold: bool, answer: &str) -> Result<(), String> {
+        let part = self.0.iter().filter(|submission| submission.gold == gold);
+        if let Some(correct) = part
+            .clone()
+            .find(|submission| submission.verdict == Verdict::Correct)
+        {
+            return Err(if correct.answer == answer {
+                format!("{} was already accepted", answer)
+            } else {
+                format!("this part was already solved with {}", correct.answer)
+            });
+        }
+        if let Some(rejected) = part
+            .clone()
+            .find(|submission| submission.verdict.is_rejection() && submission.answer == answer)
+        {
+            return Err(format!(
+                "{} was already rejected as {}",
+                answer,
+                rejected.verdict.label()
+            ));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            for submission in part {
+                let Ok(bound) = submission.answer.parse::<i128>() else {
+                    continue;
+                };
+                match submission.verdict {
+                    Verdict::TooHigh if value >= bound => {
+                        return Err(format!("{} is too high, {} already was", answer, bound));
+                    }
+                    Verdict::TooLow if value <= bound => {
+                        return Err(format!("{} is too low, {} already was", answer, bound));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Downloads inputs and submits answers, keeping requests at least
+/// `min_interval` apart across runs through the `.aoc-throttle` timestamp in
+/// the data directory.
+pub struct Client {
+    http: Box<dyn Http>,
+    base_url: String,
+    session: String,
+    root: PathBuf,
+    min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(http: Box<dyn Http>, session: String, root: &Path) -> Self {
+        Self {
+            http,
+            base_url: BASE_URL.to_owned(),
+            session,
+            root: root.to_owned(),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    fn throttle(&self) -> Result<(), Box<dyn Error>> {
+        let path = self.root.join(".aoc-throttle");
+        let last = match fs::read_to_string(&path) {
+            Ok(millis) => Duration::from_millis(millis.trim().parse().unwrap_or_default()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Duration::ZERO,
+            Err(err) => return Err(err.into()),
+        };
+        if let Some(wait) = (last + self.min_interval).checked_sub(now()) {
+            thread::sleep(wait);
+        }
+        fs::write(&path, now().as_millis().to_string())?;
+        Ok(())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        data_dir(&self.root, year).join(format!("day{:02}-input", day))
+    }
+
+    /// Downloads the input of a day unless it's cached or `force` is set,
+    /// returning its path and whether it was downloaded.
+    pub fn fetch(
+        &self,
+        year: u16,
+        day: u8,
+        force: bool,
+    ) -> Result<(PathBuf, bool), Box<dyn Error>> {
+        let path = self.input_path(year, day);
+        if !force && path.is_file() {
+            return Ok((path, false));
+        }
+
+        self.throttle()?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.http.get(&url, &self.cookie())?;
+        match response.status {
+            200 => {}
+            404 => {
+                return Err(format!("the input of {}/{:02} isn't available yet", year, day).into())
+            }
+            400 | 500 => {
+                return Err(format!(
+                    "the server refused the request ({}), is the session token valid?",
+                    response.status
+                )
+                .into())
+            }
+            status => return Err(format!("unexpected response {} for {}", status, url).into()),
+        }
+
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, response.body)?;
+        Ok((path, true))
+    }
+
+    /// Submits an answer unless the submission log shows it's pointless,
+    /// logging the verdict. A correct answer is also added to the `.answers`
+    /// file of the input for `verify`.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        gold: bool,
+        answer: &str,
+    ) -> Result<Verdict, Box<dyn Error>> {
+        // The submission log is tab-separated, one submission per line.
+        if answer.contains(['\n', '\t']) {
+            return Err(format!(
+                "{:?} can't be submitted, answers are a single line without tabs",
+                answer
+            )
+            .into());
+        }
+        let log = Submissions::path(&self.root, year, day);
+        Submissions::load(&log)?.check(gold, answer)?;
+
+        self.throttle()?;
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = if gold { "2" } else { "1" };
+        let response = self.http.post(
+            &url,
+            &self.cookie(),
+            &[("level", level), ("answer", answer)],
+        )?;
+        if response.status != 200 {
+            return Err(format!("unexpected response {} for {}", response.status, url).into());
+        }
+
+        let verdict = Verdict::from_page(&response.body);
+        fs::create_dir_all(log.parent().unwrap())?;
+        Submissions::append(
+            &log,
+            &Submission {
+                time: now().as_secs(),
+                gold,
+                answer: answer.to_owned(),
+                verdict,
+            },
+        )?;
+        if verdict == Verdict::Correct {
+            self.record_answer(year, day, gold, answer)?;
+        }
+        Ok(verdict)
+    }
+
+    fn record_answer(
+        &self,
+        year: u16,
+        day: u8,
+        gold: bool,
+        answer: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let input = self.input_path(year, day);
+        let expected = Expected::load(&input)?.unwrap_or_default();
+        if if gold {
+            expected.gold.is_some()
+        } else {
+            expected.silver.is_some()
+        } {
+            return Ok(());
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(sidecar_path(&input))?;
+        file.write_all(answers_entry(if gold { "gold" } else { "silver" }, answer).as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read},
+        net::TcpListener,
+        process,
+        sync::{Arc, Mutex},
+        thread::JoinHandle,
+        time::Instant,
+    };
+
+    use super::*;
+
+    /// A local HTTP server answering one request per connection with the next
+    /// canned response, recording the requests it got.
+    struct Stub {
+        url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+        handle: JoinHandle<()>,
+    }
+
+    impl Stub {
+        fn new(responses: Vec<(u16, &'static str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&requests);
+            let handle = thread::spawn(move || {
+                for (status, body) in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        let line = line.trim_end();
+                        if line.is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(": ") {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                length = value.parse().unwrap();
+                            }
+                            if !name.eq_ignore_ascii_case("cookie") {
+                                continue;
+                            }
+                        }
+                        request.push_str(line);
+                        request.push('\n');
+                    }
+                    let mut content = vec![0; length];
+                    reader.read_exact(&mut content).unwrap();
+                    request.push_str(&String::from_utf8(content).unwrap());
+                    recorded.lock().unwrap().push(request);
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+            Self {
+                url,
+                requests,
+                handle,
+            }
+        }
+
+        fn requests(self) -> Vec<String> {
+            self.handle.join().unwrap();
+            Arc::try_unwrap(self.requests)
+                .unwrap()
+                .into_inner()
+                .unwrap()
+        }
+    }
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-client-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    fn client(stub: &Stub, root: &Path) -> Client {
+        let mut client = Client::new(Box::new(UreqHttp::new()), "abc".to_owned(), root);
+        client.base_url = stub.url.clone();
+        client.min_interval = Duration::ZERO;
+        client
+    }
+
+    #[test]
+    fn fetch_caches_the_input() {
+        let root = temp_root("fetch");
+        let stub = Stub::new(vec![(200, "1 2\n3 4\n")]);
+        let client = client(&stub, &root);
+
+        let (path, fetched) = client.fetch(2024, 5, false).unwrap();
+        assert!(fetched);
+        assert_eq!(path, root.join("2024-data").join("day05-input"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        assert_eq!(client.fetch(2024, 5, false).unwrap(), (path, false));
+
+        assert_eq!(
+            stub.requests(),
+            ["GET /2024/day/5/input HTTP/1.1\nCookie: session=abc\n"]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn fetch_reports_unavailable_inputs() {
+        let root = temp_root("unavailable");
+        let stub = Stub::new(vec![(404, "Not found")]);
+        let client = client(&stub, &root);
+
+        assert!(client.fetch(2024, 25, false).is_err());
+        assert!(!client.input_path(2024, 25).exists());
+        stub.requests();
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn submit_logs_verdicts_and_refuses_known_wrong_answers() {
+        let root = temp_root("submit");
+        let stub = Stub::new(vec![
+            (
+                200,
+                "<p>That's not the right answer; your answer is too high.</p>",
+            ),
+            (
+                200,
+                "<p>That's the right answer! You are one gold star closer.</p>",
+            ),
+        ]);
+        let client = client(&stub, &root);
+
+        assert_eq!(
+            client.submit(2024, 5, false, "100").unwrap(),
+            Verdict::TooHigh
+        );
+        assert!(client.submit(2024, 5, false, "100").is_err());
+        assert!(client.submit(2024, 5, false, "150").is_err());
+        assert_eq!(
+            client.submit(2024, 5, false, "42").unwrap(),
+            Verdict::Correct
+        );
+        assert!(client.submit(2024, 5, false, "43").is_err());
+
+        let submissions = Submissions::load(&Submissions::path(&root, 2024, 5)).unwrap();
+        assert_eq!(
+            submissions
+                .0
+                .iter()
+                .map(|submission| (
+                    submission.gold,
+                    submission.answer.as_str(),
+                    submission.verdict
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (false, "100", Verdict::TooHigh),
+                (false, "42", Verdict::Correct)
+            ]
+        );
+        let expected = Expected::load(&client.input_path(2024, 5))
+            .unwrap()
+            .unwrap();
+        assert_eq!(expected.silver.as_deref(), Some("42"));
+        assert_eq!(
+            stub.requests(),
+            [
+                "POST /2024/day/5/answer HTTP/1.1\nCookie: session=abc\nlevel=1&answer=100",
+                "POST /2024/day/5/answer HTTP/1.1\nCookie: session=abc\nlevel=1&answer=42",
+            ]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn submit_refuses_multi_line_answers() {
+        let root = temp_root("multi-line");
+        let stub = Stub::new(Vec::new());
+        let client = client(&stub, &root);
+
+        let screen = "##..\n#..#";
+        assert!(client.submit(2022, 10, true, screen).is_err());
+        assert!(client.submit(2022, 10, true, "EHZ\tFZ").is_err());
+        assert!(!Submissions::path(&root, 2022, 10).exists());
+        assert!(stub.requests().is_empty());
+
+        let input = client.input_path(2022, 10);
+        fs::create_dir_all(input.parent().unwrap()).unwrap();
+        client.record_answer(2022, 10, true, screen).unwrap();
+        assert_eq!(
+            fs::read_to_string(sidecar_path(&input)).unwrap(),
+            "gold:\n  ##..\n  #..#\n"
+        );
+        let expected = Expected::load(&input).unwrap().unwrap();
+        assert_eq!(expected.gold.as_deref(), Some(screen));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn requests_are_throttled() {
+        let root = temp_root("throttle");
+        let stub = Stub::new(vec![(200, "1\n"), (200, "1\n")]);
+        let mut client = client(&stub, &root);
+        client.min_interval = Duration::from_millis(300);
+
+        let start = Instant::now();
+        client.fetch(2024, 1, true).unwrap();
+        client.fetch(2024, 1, true).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        stub.requests();
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn verdicts_are_read_from_the_page() {
+        assert_eq!(
+            Verdict::from_page("That's not the right answer. If you're stuck"),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_page("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_page("You gave an answer too recently; you have to wait"),
+            Verdict::TooRecent
+        );
+        assert_eq!(
+            Verdict::from_page("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::from_page("<html></html>"), Verdict::Unknown);
+    }
+}
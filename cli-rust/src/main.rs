@@ -0,0 +1,428 @@
+use std::{
+    env,
+    error::Error,
+    fs, io, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common_rs::{
+    bench::{self, read_lines, Bench},
+    day::{run, run_all, Args, Failure, ParamKind, Puzzle, Registry},
+    error::AocError,
+    heap::CountingAllocator,
+    input::Input,
+    logging::Logger,
+    report::{Format, Reporter},
+    stepper,
+    verify::{verify, Expected, Status},
+};
+use clap::ArgMatches;
+use itertools::Itertools;
+
+use client::{Client, UreqHttp, Verdict};
+
+mod cli;
+mod client;
+mod scaffold;
+
+/// Only counts once `--mem` asks for it.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn registry() -> Registry {
+    Registry::new()
+        .with(aoc22_rs::PUZZLES)
+        .with(aoc23_rs::PUZZLES)
+        .with(aoc24_rs::PUZZLES)
+        .with(aoc25_rs::PUZZLES)
+}
+
+fn describe_params(puzzle: &Puzzle) -> String {
+    puzzle
+        .all_params()
+        .map(|param| match param.kind {
+            ParamKind::Flag => format!("--{}", param.name),
+            ParamKind::Count => format!("--{}...", param.name),
+            ParamKind::Value { required: true } => format!("--{} <{}>", param.name, param.name),
+            ParamKind::Value { required: false } => format!("[--{} <{}>]", param.name, param.name),
+        })
+        .join(" ")
+}
+
+fn list(registry: &Registry) {
+    for puzzle in registry.iter() {
+        let line = format!(
+            "{} {:>2}  {:<32} {:<10} {}",
+            puzzle.year,
+            puzzle.number,
+            puzzle.title,
+            puzzle.parts.label(),
+            describe_params(puzzle)
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+fn cell(value: &Option<String>) -> String {
+    match value {
+        Some(value) if value.contains('\n') => {
+            format!("{}...", value.lines().next().unwrap_or_default())
+        }
+        Some(value) => value.clone(),
+        None => "-".to_owned(),
+    }
+}
+
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let header = header.map(String::from);
+    let widths: Vec<usize> = (0..N)
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for row in [&header].into_iter().chain(rows) {
+        println!(
+            "{}",
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .join("  ")
+                .trim_end()
+        );
+    }
+}
+
+fn verify_all(
+    registry: &Registry,
+    data_dir: &Path,
+    year: Option<u16>,
+    number: Option<u8>,
+) -> Result<bool, Box<dyn Error>> {
+    let puzzles = registry.iter().filter(|puzzle| {
+        year.is_none_or(|year| puzzle.year == year)
+            && number.is_none_or(|number| puzzle.number == number)
+    });
+
+    // Panicking solutions are reported in the table instead.
+    panic::set_hook(Box::new(|_| {}));
+    let mut rows = Vec::new();
+    for puzzle in puzzles {
+        for outcome in verify(data_dir, puzzle)? {
+            rows.push([
+                outcome.year.to_string(),
+                outcome.number.to_string(),
+                outcome
+                    .input
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                outcome.part.unwrap_or("-").to_owned(),
+                cell(&outcome.expected),
+                cell(&outcome.actual),
+                outcome.status.label().to_owned(),
+            ]);
+        }
+    }
+    let _ = panic::take_hook();
+
+    print_table(
+        [
+            "year", "day", "input", "part", "expected", "actual", "status",
+        ],
+        &rows,
+    );
+
+    let count = |status: Status| rows.iter().filter(|row| row[6] == status.label()).count();
+    let (passed, failed, missing, errors) = (
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Error),
+    );
+    println!(
+        "{} passed, {} failed, {} missing, {} errors",
+        passed, failed, missing, errors
+    );
+    Ok(failed == 0 && errors == 0)
+}
+
+fn run_year(
+    registry: &Registry,
+    data_dir: &Path,
+    year: u16,
+    sample: bool,
+    jobs: usize,
+) -> Result<bool, Box<dyn Error>> {
+    // Panicking solutions are reported in the table instead.
+    panic::set_hook(Box::new(|_| {}));
+    let start_time = Instant::now();
+    let runs = run_all(registry.year(year), data_dir, sample, jobs);
+    let elapsed = start_time.elapsed();
+    let _ = panic::take_hook();
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for run in &runs {
+        let (silver, gold, status) = match &run.result {
+            Ok(answers) => (
+                cell(
+                    &answers
+                        .silver
+                        .as_ref()
+                        .map(|answer| answer.value.to_string()),
+                ),
+                cell(&answers.gold.as_ref().map(|answer| answer.value.to_string())),
+                "ok",
+            ),
+            Err(failure) => {
+                let status = match failure {
+                    Failure::Unsolved => "unsolved",
+                    Failure::MissingInput(_) => "no input",
+                    Failure::Error(_) => {
+                        errors.push((run.puzzle.number, failure));
+                        "error"
+                    }
+                };
+                ("-".to_owned(), "-".to_owned(), status)
+            }
+        };
+        rows.push([
+            run.puzzle.number.to_string(),
+            run.puzzle.title.to_owned(),
+            silver,
+            gold,
+            format!("{:.2?}", run.time),
+            status.to_owned(),
+        ]);
+    }
+    print_table(["day", "title", "silver", "gold", "time", "status"], &rows);
+
+    let count = |status: &str| rows.iter().filter(|row| row[5] == status).count();
+    println!(
+        "{} solved, {} unsolved, {} without input, {} errors in {:.2?} ({:.2?} wall)",
+        count("ok"),
+        count("unsolved"),
+        count("no input"),
+        count("error"),
+        runs.iter().map(|run| run.time).sum::<Duration>(),
+        elapsed
+    );
+    for (number, failure) in &errors {
+        eprintln!("Day {}: {}", number, failure);
+    }
+    Ok(errors.is_empty())
+}
+
+fn bench(
+    puzzle: &Puzzle,
+    args: &Args,
+    input: &Input,
+    matches: &ArgMatches,
+    reporter: &dyn Reporter,
+) -> Result<bool, Box<dyn Error>> {
+    let baseline = match matches.get_one::<PathBuf>("baseline") {
+        Some(path) => Some(Bench::from_json(&fs::read_to_string(path)?, puzzle)?),
+        None => None,
+    };
+    let lines = read_lines(input.open()?)?;
+    let runs = *matches.get_one::<usize>("bench").unwrap();
+    let warmup = *matches.get_one::<usize>("warmup").unwrap();
+    let (result, answers) = bench::bench(puzzle, args, &lines, runs, warmup)?;
+
+    let mut out = io::stdout().lock();
+    reporter.report(&mut out, puzzle, &answers)?;
+    result.write_report(&mut out, warmup)?;
+    if let Some(path) = matches.get_one::<PathBuf>("save-baseline") {
+        fs::write(path, result.to_json(puzzle) + "\n")?;
+    }
+    if let Some(baseline) = baseline {
+        let tolerance = matches.get_one::<f64>("tolerance").unwrap() / 100.0;
+        let regressions = result.write_comparison(&mut out, &baseline, tolerance)?;
+        return Ok(regressions.is_empty());
+    }
+    Ok(true)
+}
+
+fn client(data_dir: &Path) -> Result<Client, Box<dyn Error>> {
+    Ok(Client::new(
+        Box::new(UreqHttp::new()),
+        client::session(data_dir)?,
+        data_dir,
+    ))
+}
+
+fn submit(
+    registry: &Registry,
+    data_dir: &Path,
+    matches: &ArgMatches,
+) -> Result<bool, Box<dyn Error>> {
+    let year = *matches.get_one::<u16>("year").unwrap();
+    let number = *matches.get_one::<u8>("day").unwrap();
+    let gold = matches.get_one::<String>("part").unwrap() == "gold";
+    let client = client(data_dir)?;
+
+    let answer = match matches.get_one::<String>("answer") {
+        Some(answer) => answer.clone(),
+        None => {
+            let puzzle = registry
+                .find(year, number)
+                .ok_or_else(|| format!("there is no solution for {}/{:02}", year, number))?;
+            let (input, _) = client.fetch(year, number, false)?;
+            let args =
+                Expected::load(&input)?
+                    .unwrap_or_default()
+                    .args(puzzle, &input, Some(gold))?;
+            let answers = puzzle.solve(&fs::read_to_string(&input)?, &args)?;
+            answers
+                .part(gold)
+                .ok_or("the solution doesn't answer that part")?
+                .value
+                .to_string()
+        }
+    };
+
+    println!(
+        "Submitting {} to {}/{:02} {}",
+        answer,
+        year,
+        number,
+        if gold { "gold" } else { "silver" }
+    );
+    let verdict = client.submit(year, number, gold, &answer)?;
+    println!("{}: {}", verdict.label(), verdict);
+    Ok(verdict == Verdict::Correct)
+}
+
+fn try_main() -> Result<ExitCode, Box<dyn Error>> {
+    let registry = registry();
+    let matches = cli::command(&registry).get_matches();
+    let timed = matches.get_flag("timed");
+    let format: Format = matches.get_one::<String>("format").unwrap().parse()?;
+    let data_dir = matches.get_one::<PathBuf>("data-dir").unwrap();
+    Logger::from_verbosity(matches.get_flag("quiet"), matches.get_count("verbose"))
+        .with_filters(&env::var("AOC_LOG").unwrap_or_default())?
+        .init()?;
+
+    match matches.subcommand() {
+        Some(("list", _)) => {
+            list(&registry);
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(("verify", verify_matches)) => {
+            let success = verify_all(
+                &registry,
+                data_dir,
+                verify_matches.get_one::<u16>("year").copied(),
+                verify_matches.get_one::<u8>("day").copied(),
+            )?;
+            Ok(if success {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Some(("new-day", new_day_matches)) => {
+            let number = *new_day_matches.get_one::<u8>("day").unwrap();
+            let title = match new_day_matches.get_one::<String>("title") {
+                Some(title) => title.clone(),
+                None => format!("Day {}", number),
+            };
+            let files = scaffold::new_day(
+                data_dir,
+                *new_day_matches.get_one::<u16>("year").unwrap(),
+                number,
+                &title,
+            )?;
+            for file in files {
+                println!("Wrote {}", file.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(("fetch", fetch_matches)) => {
+            let (path, fetched) = client(data_dir)?.fetch(
+                *fetch_matches.get_one::<u16>("year").unwrap(),
+                *fetch_matches.get_one::<u8>("day").unwrap(),
+                fetch_matches.get_flag("force"),
+            )?;
+            if fetched {
+                println!("Downloaded {}", path.display());
+            } else {
+                println!("{} is already there", path.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(("submit", submit_matches)) => {
+            let success = submit(&registry, data_dir, submit_matches)?;
+            Ok(if success {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Some((year, year_matches)) if year_matches.get_flag("all") => {
+            let success = run_year(
+                &registry,
+                data_dir,
+                year.parse()?,
+                year_matches.get_flag("sample"),
+                match year_matches.get_one::<usize>("jobs") {
+                    Some(jobs) => *jobs,
+                    None => thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+                },
+            )?;
+            Ok(if success {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Some((year, year_matches)) => {
+            let (number, day_matches) = year_matches.subcommand().unwrap();
+            let puzzle = registry.find(year.parse()?, number.parse()?).unwrap();
+            let input = cli::input(data_dir, puzzle, day_matches)?;
+            let args = cli::args(puzzle, &input, day_matches);
+            // Stepping reads its commands from stdin, so check it before the
+            // input is read.
+            if args.flag("step") {
+                stepper::check_terminal()?;
+            }
+            let reporter = format.reporter();
+            if day_matches.contains_id("bench") {
+                let success = bench(puzzle, &args, &input, day_matches, reporter.as_ref())?;
+                return Ok(if success {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::FAILURE
+                });
+            }
+            run(
+                puzzle,
+                &args,
+                &input,
+                timed,
+                matches.get_flag("mem"),
+                reporter.as_ref(),
+            )?;
+            Ok(ExitCode::SUCCESS)
+        }
+        None => unreachable!(),
+    }
+}
+
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            AocError::find(err.as_ref()).map_or(ExitCode::FAILURE, |err| err.exit_code().into())
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use aoc_common_rs::verify::data_dir;
+use log::warn;
+
+const TEMPLATE: &str = r#"use std::error::Error;
+
+use aoc_common_rs::{
+    answers::Answers,
+    day::{Day, Parts, Puzzle},
+    line_stream::LineStreamHandler,
+};
+
+#[derive(Default)]
+struct DayN {}
+
+impl DayN {
+    fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl LineStreamHandler for DayN {
+    fn update(&mut self, _line: &str) -> Result<(), Box<dyn Error>> {
+        todo!()
+    }
+
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        todo!()
+    }
+}
+
+pub fn new() -> Result<Day, Box<dyn Error>> {
+    Ok(Day::new(DayN::new()))
+}
+
+pub const PUZZLE: Puzzle = Puzzle::new(YEAR, NUMBER, TITLE, Parts::Unsolved, |_| new());
+"#;
+
+const ANSWERS_TEMPLATE: &str = "# silver: \n# gold: \n";
+
+/// Adds `mod dayNN;` and `dayNN::PUZZLE,` to the source of a year's
+/// `lib.rs`, keeping both lists sorted.
+pub fn register(lib: &str, number: u8) -> Result<String, Box<dyn Error>> {
+    let module = format!("day{:02}", number);
+    let declaration = format!("mod {};", module);
+    let entry = format!("    {}::PUZZLE,", module);
+    let lines: Vec<&str> = lib.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Err(format!("{} is already registered", module).into());
+    }
+
+    let declarations: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("mod day"))
+        .collect();
+    let declaration_at = match declarations
+        .iter()
+        .find(|&&i| lines[i] > declaration.as_str())
+    {
+        Some(&i) => i,
+        None => {
+            declarations
+                .last()
+                .ok_or("no day modules found in lib.rs")?
+                + 1
+        }
+    };
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const PUZZLES"))
+        .ok_or("no PUZZLES found in lib.rs")?;
+    let end = (start..lines.len())
+        .find(|&i| lines[i] == "];")
+        .ok_or("unterminated PUZZLES in lib.rs")?;
+    let entry_at = (start + 1..end)
+        .find(|&i| lines[i] > entry.as_str())
+        .unwrap_or(end);
+
+    let mut registered = Vec::with_capacity(lines.len() + 2);
+    for (i, line) in lines.iter().enumerate() {
+        if i == declaration_at {
+            registered.push(declaration.as_str());
+        }
+        if i == entry_at {
+            registered.push(entry.as_str());
+        }
+        registered.push(line);
+    }
+    Ok(registered.join("\n") + "\n")
+}
+
+/// Reads the `edition` of a crate from its `Cargo.toml`.
+fn edition(manifest: &str) -> Option<&str> {
+    manifest.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "edition").then(|| value.trim().trim_matches('"'))
+    })
+}
+
+/// Creates the module of a day in the `YYYY-rust` crate under `root` from the
+/// template, registers it and adds an empty sample with its answers file to
+/// the year's data folder. Returns the files created or updated.
+pub fn new_day(
+    root: &Path,
+    year: u16,
+    number: u8,
+    title: &str,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let krate = root.join(format!("{}-rust", year));
+    let manifest_path = krate.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|err| format!("cannot read {}: {}", manifest_path.display(), err))?;
+    let edition =
+        edition(&manifest).ok_or_else(|| format!("no edition in {}", manifest_path.display()))?;
+    let src = krate.join("src");
+    let lib_path = src.join("lib.rs");
+    let lib = fs::read_to_string(&lib_path)
+        .map_err(|err| format!("cannot read {}: {}", lib_path.display(), err))?;
+    let module = src.join(format!("day{:02}", number));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()).into());
+    }
+    let lib = register(&lib, number)?;
+
+    let mod_path = module.join("mod.rs");
+    fs::create_dir(&module)?;
+    fs::write(
+        &mod_path,
+        TEMPLATE
+            .replace("DayN", &format!("Day{}", number))
+            .replace("YEAR", &year.to_string())
+            .replace("NUMBER", &number.to_string())
+            .replace("TITLE", &format!("{:?}", title)),
+    )?;
+    // Long titles need the `PUZZLE` line wrapped.
+    match Command::new("rustfmt")
+        .args(["--edition", edition])
+        .arg(&mod_path)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        Ok(status) => warn!("rustfmt failed on {}: {}", mod_path.display(), status),
+        Err(err) => warn!("cannot run rustfmt on {}: {}", mod_path.display(), err),
+    }
+    fs::write(&lib_path, lib)?;
+    let mut files = vec![mod_path, lib_path];
+
+    let data = data_dir(root, year);
+    fs::create_dir_all(&data)?;
+    let sample = data.join(format!("day{:02}-sample", number));
+    for (path, contents) in [
+        (sample.clone(), ""),
+        (
+            sample.with_file_name(format!("day{:02}-sample.answers", number)),
+            ANSWERS_TEMPLATE,
+        ),
+    ] {
+        if !path.exists() {
+            fs::write(&path, contents)?;
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    const LIB: &str = "use aoc_common_rs::day::Puzzle;
+
+mod day01;
+mod day03;
+
+pub const PUZZLES: &[Puzzle] = &[
+    day01::PUZZLE,
+    day03::PUZZLE,
+];
+";
+
+    #[test]
+    fn registers_days_in_order() {
+        assert_eq!(
+            register(LIB, 2).unwrap(),
+            "use aoc_common_rs::day::Puzzle;
+
+mod day01;
+mod day02;
+mod day03;
+
+pub const PUZZLES: &[Puzzle] = &[
+    day01::PUZZLE,
+    day02::PUZZLE,
+    day03::PUZZLE,
+];
+"
+        );
+        assert_eq!(
+            register(LIB, 12).unwrap(),
+            "use aoc_common_rs::day::Puzzle;
+
+mod day01;
+mod day03;
+mod day12;
+
+pub const PUZZLES: &[Puzzle] = &[
+    day01::PUZZLE,
+    day03::PUZZLE,
+    day12::PUZZLE,
+];
+"
+        );
+        assert!(register(LIB, 3).is_err());
+    }
+
+    #[test]
+    fn reads_the_edition() {
+        assert_eq!(
+            edition("[package]\nname = \"aoc25-rs\"\nedition = \"2024\"\n"),
+            Some("2024")
+        );
+        assert_eq!(edition("[package]\nname = \"aoc25-rs\"\n"), None);
+    }
+
+    #[test]
+    fn creates_a_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let src = root.join("2025-rust").join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(
+            root.join("2025-rust").join("Cargo.toml"),
+            "[package]\nname = \"aoc25-rs\"\nedition = \"2024\"\n",
+        )
+        .unwrap();
+        fs::write(src.join("lib.rs"), LIB).unwrap();
+
+        let data = root.join("2025-data");
+        assert_eq!(
+            new_day(&root, 2025, 2, "Gift Shop").unwrap(),
+            [
+                src.join("day02").join("mod.rs"),
+                src.join("lib.rs"),
+                data.join("day02-sample"),
+                data.join("day02-sample.answers"),
+            ]
+        );
+        let module = fs::read_to_string(src.join("day02").join("mod.rs")).unwrap();
+        assert!(module.contains("struct Day2 {}"));
+        assert!(module.contains("Puzzle::new(2025, 2, \"Gift Shop\", Parts::Unsolved"));
+        assert_eq!(
+            fs::read_to_string(src.join("lib.rs")).unwrap(),
+            register(LIB, 2).unwrap()
+        );
+        assert_eq!(
+            fs::read_to_string(data.join("day02-sample.answers")).unwrap(),
+            ANSWERS_TEMPLATE
+        );
+
+        let err = new_day(&root, 2025, 2, "Gift Shop").unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{}", err);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
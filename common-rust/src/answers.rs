@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Value {
+    Integer(i128),
+    Text(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Integer(value) => Display::fmt(value, f),
+            Self::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+macro_rules! impl_value_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Self::Integer(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_value_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+impl From<isize> for Value {
+    fn from(value: isize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+impl From<u128> for Value {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => Self::Integer(value),
+            Err(_) => Self::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Answer {
+    pub label: String,
+    pub value: Value,
+}
+
+impl Answer {
+    pub fn new(label: impl Into<String>, value: impl Into<Value>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Answers {
+    pub silver: Option<Answer>,
+    pub gold: Option<Answer>,
+    pub extras: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_silver(mut self, label: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.silver = Some(Answer::new(label, value));
+        self
+    }
+
+    pub fn with_gold(mut self, label: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.gold = Some(Answer::new(label, value));
+        self
+    }
+
+    pub fn with_part(self, gold: bool, label: impl Into<String>, value: impl Into<Value>) -> Self {
+        if gold {
+            self.with_gold(label, value)
+        } else {
+            self.with_silver(label, value)
+        }
+    }
+
+    pub fn with_extra(mut self, label: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.push_extra(label, value);
+        self
+    }
+
+    pub fn push_extra(&mut self, label: impl Into<String>, value: impl Into<Value>) {
+        self.extras.push(Answer::new(label, value));
+    }
+
+    pub fn part(&self, gold: bool) -> Option<&Answer> {
+        if gold {
+            self.gold.as_ref()
+        } else {
+            self.silver.as_ref()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.silver.is_none() && self.gold.is_none() && self.extras.is_empty()
+    }
+}
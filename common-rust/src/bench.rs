@@ -0,0 +1,269 @@
+use std::{
+    error::Error,
+    io::{self, BufRead, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::Answers,
+    day::{Args, Puzzle},
+    error::AocError,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &mut [Duration]) -> Self {
+        if samples.is_empty() {
+            return Default::default();
+        }
+        samples.sort();
+        let rank = |percentile: usize| samples[(samples.len() * percentile).div_ceil(100) - 1];
+        Self {
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Bench {
+    pub runs: usize,
+    pub update: Stats,
+    pub finish: Stats,
+    pub total: Stats,
+}
+
+pub const PHASES: [&str; 3] = ["update", "finish", "total"];
+
+impl Bench {
+    pub fn phase(&self, name: &str) -> Option<&Stats> {
+        match name {
+            "update" => Some(&self.update),
+            "finish" => Some(&self.finish),
+            "total" => Some(&self.total),
+            _ => None,
+        }
+    }
+
+    pub fn to_json(&self, puzzle: &Puzzle) -> String {
+        let stats = |stats: &Stats| {
+            format!(
+                "{{\"min\":{},\"median\":{},\"p95\":{}}}",
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos()
+            )
+        };
+        format!(
+            "{{\"year\":{},\"day\":{},\"runs\":{},\"update\":{},\"finish\":{},\"total\":{}}}",
+            puzzle.year,
+            puzzle.number,
+            self.runs,
+            stats(&self.update),
+            stats(&self.finish),
+            stats(&self.total)
+        )
+    }
+
+    /// Reads back the output of `to_json`, which must have been saved for the
+    /// same puzzle.
+    pub fn from_json(json: &str, puzzle: &Puzzle) -> Result<Self, Box<dyn Error>> {
+        let number = |json: &str, key: &str| -> Result<u64, Box<dyn Error>> {
+            let pattern = format!("\"{}\":", key);
+            let start = json
+                .find(&pattern)
+                .ok_or_else(|| format!("missing {} in baseline", key))?
+                + pattern.len();
+            let digits = json[start..]
+                .trim_start()
+                .split(|ch: char| !ch.is_ascii_digit())
+                .next()
+                .unwrap_or_default();
+            Ok(digits.parse()?)
+        };
+        let stats = |phase: &str| -> Result<Stats, Box<dyn Error>> {
+            let pattern = format!("\"{}\":", phase);
+            let start = json
+                .find(&pattern)
+                .ok_or_else(|| format!("missing {} in baseline", phase))?;
+            let object = &json[start..];
+            let object = &object[..object.find('}').unwrap_or(object.len())];
+            Ok(Stats {
+                min: Duration::from_nanos(number(object, "min")?),
+                median: Duration::from_nanos(number(object, "median")?),
+                p95: Duration::from_nanos(number(object, "p95")?),
+            })
+        };
+        let (year, day) = (number(json, "year")?, number(json, "day")?);
+        if (year, day) != (puzzle.year as u64, puzzle.number as u64) {
+            return Err(AocError::invalid_input(format!(
+                "the baseline is for {} day {}, not {} day {}",
+                year, day, puzzle.year, puzzle.number
+            ))
+            .into());
+        }
+        Ok(Self {
+            runs: number(json, "runs")? as usize,
+            update: stats("update")?,
+            finish: stats("finish")?,
+            total: stats("total")?,
+        })
+    }
+
+    pub fn write_report(&self, out: &mut dyn Write, warmup: usize) -> io::Result<()> {
+        writeln!(out, "--- Bench: {} runs, {} warm-up ---", self.runs, warmup)?;
+        writeln!(
+            out,
+            "{:<8} {:>12} {:>12} {:>12}",
+            "phase", "min", "median", "p95"
+        )?;
+        for name in PHASES {
+            let stats = self.phase(name).unwrap();
+            writeln!(
+                out,
+                "{:<8} {:>12} {:>12} {:>12}",
+                name,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95)
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Compares the medians with a baseline, returning the phases that got
+    /// slower by more than `tolerance` (e.g. `0.1` for 10%).
+    pub fn write_comparison(
+        &self,
+        out: &mut dyn Write,
+        baseline: &Bench,
+        tolerance: f64,
+    ) -> io::Result<Vec<&'static str>> {
+        let mut regressions = Vec::new();
+        writeln!(out, "--- Baseline comparison (medians) ---")?;
+        for name in PHASES {
+            let before = baseline.phase(name).unwrap().median;
+            let after = self.phase(name).unwrap().median;
+            let ratio = after.as_secs_f64() / before.as_secs_f64().max(f64::MIN_POSITIVE);
+            let verdict = if ratio > 1.0 + tolerance {
+                regressions.push(name);
+                "regressed"
+            } else if ratio < 1.0 - tolerance {
+                "improved"
+            } else {
+                "unchanged"
+            };
+            writeln!(
+                out,
+                "{:<8} {:>12} -> {:>12} {:>+8.1}% {}",
+                name,
+                format!("{:.2?}", before),
+                format!("{:.2?}", after),
+                (ratio - 1.0) * 100.0,
+                verdict
+            )?;
+        }
+        Ok(regressions)
+    }
+}
+
+pub fn read_lines<R: BufRead>(input: R) -> io::Result<Vec<String>> {
+    input.lines().collect()
+}
+
+/// Runs a day over buffered lines `warmup + runs` times, timing the `update`
+/// and `finish` phases of the last `runs` separately.
+pub fn bench(
+    puzzle: &Puzzle,
+    args: &Args,
+    lines: &[String],
+    runs: usize,
+    warmup: usize,
+) -> Result<(Bench, Answers), Box<dyn Error>> {
+    let mut update = Vec::with_capacity(runs);
+    let mut finish = Vec::with_capacity(runs);
+    let mut total = Vec::with_capacity(runs);
+    let mut answers = Answers::new();
+    for run in 0..warmup + runs.max(1) {
+        let day = puzzle.create(args)?;
+        let start_time = Instant::now();
+        for (i, line) in lines.iter().enumerate() {
+            day.update_line(i + 1, line)?;
+        }
+        let update_time = start_time.elapsed();
+        answers = day.finish()?;
+        let total_time = start_time.elapsed();
+        if run >= warmup {
+            update.push(update_time);
+            finish.push(total_time - update_time);
+            total.push(total_time);
+        }
+    }
+    let bench = Bench {
+        runs: total.len(),
+        update: Stats::new(&mut update),
+        finish: Stats::new(&mut finish),
+        total: Stats::new(&mut total),
+    };
+    Ok((bench, answers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day::Parts;
+
+    const PUZZLE: Puzzle = Puzzle::new(2024, 6, "Guard Gallivant", Parts::Both, |_| unreachable!());
+
+    fn millis(samples: impl IntoIterator<Item = u64>) -> Vec<Duration> {
+        samples.into_iter().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let mut samples = millis((1..=20).rev());
+        let stats = Stats::new(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let seven = Duration::from_millis(7);
+        assert_eq!(
+            Stats::new(&mut [seven]),
+            Stats {
+                min: seven,
+                median: seven,
+                p95: seven
+            }
+        );
+        assert_eq!(Stats::new(&mut []), Stats::default());
+    }
+
+    #[test]
+    fn round_trips_the_baseline() {
+        let stats = |base| Stats::new(&mut millis([base, base + 1, base + 5]));
+        let bench = Bench {
+            runs: 3,
+            update: stats(10),
+            finish: stats(2),
+            total: stats(12),
+        };
+        let json = bench.to_json(&PUZZLE);
+        assert!(json.starts_with(r#"{"year":2024,"day":6,"runs":3,"update":{"min":10000000,"#));
+        assert_eq!(Bench::from_json(&json, &PUZZLE).unwrap(), bench);
+
+        let other = Puzzle::new(2024, 16, "Reindeer Maze", Parts::Both, |_| unreachable!());
+        let err = Bench::from_json(&json, &other).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("the baseline is for 2024 day 6, not 2024 day 16"));
+        assert!(Bench::from_json(&json.replace("\"p95\"", "\"p90\""), &PUZZLE).is_err());
+    }
+}
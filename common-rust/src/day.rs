@@ -1,40 +1,67 @@
 use std::{
     cell::RefCell,
+    collections::HashMap,
     error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     io::{self, BufRead},
-    time::Instant,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
 };
 
-use crate::line_stream::{wrap_once, LineStreamHandler, LineStreamHandlerOnce};
+use itertools::Itertools;
+
+use crate::{
+    answers::Answers,
+    error::AocError,
+    heap,
+    input::Input,
+    line_stream::{line_error, split_parts, wrap_once, LineStreamHandler, LineStreamHandlerOnce},
+    report::Reporter,
+    verify::{data_dir, Expected},
+};
 
 pub const GOLD_ANSI: &str = "\x1B[38;2;255;215;0mG\x1B[m";
 pub const SILVER_ANSI: &str = "\x1B[38;2;192;192;192mS\x1B[m";
 
 pub struct Day {
     pub display_banner: bool,
-    pub number: u8,
-    pub title: &'static str,
     pub handler: RefCell<Box<dyn LineStreamHandler>>,
 }
 
 impl Day {
-    pub fn new<H>(number: u8, title: &'static str, handler: H) -> Self
+    pub fn new<H>(handler: H) -> Self
     where
         H: LineStreamHandler + 'static,
     {
         Self {
             display_banner: true,
-            number,
-            title,
             handler: RefCell::new(Box::new(handler)),
         }
     }
 
-    pub fn new_once<H>(number: u8, title: &'static str, handler: H) -> Self
+    pub fn new_once<H>(handler: H) -> Self
     where
         H: LineStreamHandlerOnce + 'static,
     {
-        Self::new(number, title, wrap_once(handler))
+        Self::new(wrap_once(handler))
+    }
+
+    /// Runs the silver and the gold day side by side over the same input.
+    pub fn split_parts(silver: Day, gold: Day) -> Self {
+        Self {
+            display_banner: silver.display_banner || gold.display_banner,
+            handler: RefCell::new(Box::new(split_parts(
+                silver.handler.into_inner(),
+                gold.handler.into_inner(),
+            ))),
+        }
     }
 
     pub fn with_display_banner(mut self, display_banner: bool) -> Self {
@@ -46,26 +73,426 @@ impl Day {
         self.handler.borrow_mut().update(line)
     }
 
-    pub fn finish(self) -> Result<(), Box<dyn Error>> {
+    pub fn finish(self) -> Result<Answers, Box<dyn Error>> {
         self.handler.into_inner().finish()
     }
+
+    /// Like `update`, attributing errors to the 1-based line `number`.
+    pub fn update_line(&self, number: usize, line: &str) -> Result<(), Box<dyn Error>> {
+        self.update(line)
+            .map_err(|source| line_error(number, line, source))
+    }
+
+    pub fn solve<R: BufRead>(self, input: R) -> Result<Answers, Box<dyn Error>> {
+        for (i, line) in input.lines().enumerate() {
+            self.update_line(i + 1, line?.as_str())?;
+        }
+        self.finish()
+    }
 }
 
-pub fn run(day: Day, timed: bool) -> Result<(), Box<dyn Error>> {
-    if day.display_banner {
-        eprintln!("--- Day {}: {} ---", day.number, day.title);
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Parts {
+    /// A single run yields both the silver and the gold answer.
+    Both,
+    /// A single run yields either answer, depending on the `gold` flag. Both
+    /// parts are run side by side unless `silver` or `gold` is given.
+    Selectable,
+    /// The puzzle only has a silver answer.
+    SilverOnly,
+    /// The puzzle isn't solved yet.
+    Unsolved,
+}
+
+impl Parts {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Both => "both",
+            Self::Selectable => "selectable",
+            Self::SilverOnly => "silver",
+            Self::Unsolved => "unsolved",
+        }
     }
+}
 
-    let start_time = Instant::now();
-    let stdin = io::BufReader::new(io::stdin());
-    for line in stdin.lines() {
-        day.update(line?.as_str())?;
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParamKind {
+    Flag,
+    Count,
+    Value { required: bool },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub short: Option<char>,
+    pub kind: ParamKind,
+}
+
+impl Param {
+    pub const SILVER: Self = Self::flag("silver", 'S');
+    pub const GOLD: Self = Self::flag("gold", 'g');
+    /// Steps through the solution interactively, see `Stepper`.
+    pub const STEP: Self = Self::flag("step", 'e');
+    /// Saves a recording of the solution, see `FrameRecorder::save`.
+    pub const RECORD: Self = Self::value("record", 'r');
+
+    pub const fn flag(name: &'static str, short: char) -> Self {
+        Self {
+            name,
+            short: Some(short),
+            kind: ParamKind::Flag,
+        }
+    }
+
+    pub const fn count(name: &'static str, short: char) -> Self {
+        Self {
+            name,
+            short: Some(short),
+            kind: ParamKind::Count,
+        }
+    }
+
+    pub const fn value(name: &'static str, short: char) -> Self {
+        Self {
+            name,
+            short: Some(short),
+            kind: ParamKind::Value { required: false },
+        }
+    }
+
+    pub const fn required(name: &'static str, short: char) -> Self {
+        Self {
+            name,
+            short: Some(short),
+            kind: ParamKind::Value { required: true },
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Args {
+    counts: HashMap<&'static str, u8>,
+    values: HashMap<&'static str, String>,
+}
+
+impl Args {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn set_count(&mut self, name: &'static str, count: u8) {
+        self.counts.insert(name, count);
+    }
+
+    pub fn set_value(&mut self, name: &'static str, value: String) {
+        self.values.insert(name, value);
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.count(name) > 0
+    }
+
+    pub fn count(&self, name: &str) -> u8 {
+        self.counts.get(name).copied().unwrap_or(0)
+    }
+
+    /// Whether the parameter was given at all, be it a flag or a value.
+    pub fn is_set(&self, name: &str) -> bool {
+        self.flag(name) || self.values.contains_key(name)
+    }
+
+    pub fn value<T>(&self, name: &str) -> Result<Option<T>, Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Error + 'static,
+    {
+        match self.values.get(name) {
+            Some(value) => Ok(Some(value.parse()?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn required<T>(&self, name: &str) -> Result<T, Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Error + 'static,
+    {
+        self.value(name)?
+            .ok_or_else(|| format!("missing required parameter {}", name).into())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Puzzle {
+    pub year: u16,
+    pub number: u8,
+    pub title: &'static str,
+    pub parts: Parts,
+    pub params: &'static [Param],
+    pub new: fn(&Args) -> Result<Day, Box<dyn Error>>,
+}
+
+impl Puzzle {
+    pub const fn new(
+        year: u16,
+        number: u8,
+        title: &'static str,
+        parts: Parts,
+        new: fn(&Args) -> Result<Day, Box<dyn Error>>,
+    ) -> Self {
+        Self {
+            year,
+            number,
+            title,
+            parts,
+            params: &[],
+            new,
+        }
+    }
+
+    pub const fn with_params(mut self, params: &'static [Param]) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn all_params(&self) -> impl Iterator<Item = &Param> {
+        let parts: &'static [Param] = if matches!(self.parts, Parts::Selectable) {
+            &[Param::SILVER, Param::GOLD]
+        } else {
+            &[]
+        };
+        parts.iter().chain(self.params)
+    }
+
+    pub fn create(&self, args: &Args) -> Result<Day, Box<dyn Error>> {
+        if matches!(self.parts, Parts::Selectable) && !args.flag("silver") && !args.flag("gold") {
+            for param in [Param::STEP, Param::RECORD] {
+                if args.is_set(param.name) {
+                    return Err(AocError::invalid_input(format!(
+                        "--{} needs --silver or --gold",
+                        param.name
+                    ))
+                    .into());
+                }
+            }
+            let mut gold = args.clone();
+            gold.set_count("gold", 1);
+            return Ok(Day::split_parts((self.new)(args)?, (self.new)(&gold)?));
+        }
+        (self.new)(args)
+    }
+
+    /// Solves an input held in memory, both parts unless `args` selects one.
+    pub fn solve(&self, input: &str, args: &Args) -> Result<Answers, Box<dyn Error>> {
+        self.create(args)?.solve(input.as_bytes())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Registry {
+    puzzles: Vec<Puzzle>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with(mut self, puzzles: &[Puzzle]) -> Self {
+        self.register(puzzles);
+        self
+    }
+
+    pub fn register(&mut self, puzzles: &[Puzzle]) {
+        self.puzzles.extend_from_slice(puzzles);
+        self.puzzles
+            .sort_by_key(|puzzle| (puzzle.year, puzzle.number));
+    }
+
+    pub fn find(&self, year: u16, number: u8) -> Option<&Puzzle> {
+        self.puzzles
+            .iter()
+            .find(|puzzle| puzzle.year == year && puzzle.number == number)
+    }
+
+    pub fn years(&self) -> impl Iterator<Item = u16> + '_ {
+        self.puzzles.iter().map(|puzzle| puzzle.year).dedup()
     }
 
-    let result = day.finish();
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &Puzzle> {
+        self.puzzles
+            .iter()
+            .filter(move |puzzle| puzzle.year == year)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Puzzle> {
+        self.puzzles.iter()
+    }
+}
+
+pub fn run(
+    puzzle: &Puzzle,
+    args: &Args,
+    input: &Input,
+    timed: bool,
+    mem: bool,
+    reporter: &dyn Reporter,
+) -> Result<(), Box<dyn Error>> {
+    if mem {
+        heap::start_counting();
+    }
+    let reader = input.open()?;
+    let day = puzzle.create(args)?;
+    if day.display_banner {
+        eprintln!("--- Day {}: {} ---", puzzle.number, puzzle.title);
+    }
+
+    let start_time = Instant::now();
+    let result = if mem {
+        let (updated, update_stats) = heap::measure(|| -> Result<(), Box<dyn Error>> {
+            for (i, line) in reader.lines().enumerate() {
+                day.update_line(i + 1, line?.as_str())?;
+            }
+            Ok(())
+        });
+        eprintln!("--- Memory (update): {} ---", update_stats);
+        updated.and_then(|_| {
+            let (result, finish_stats) = heap::measure(|| day.finish());
+            eprintln!("--- Memory (finish): {} ---", finish_stats);
+            result
+        })
+    } else {
+        day.solve(reader)
+    };
     if timed {
         eprintln!("--- Time: {:?} ---", start_time.elapsed());
     }
 
-    result
+    reporter.report(&mut io::stdout().lock(), puzzle, &result?)?;
+    Ok(())
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Failure {
+    /// The solution hit a `todo!()` or an `unimplemented!()`.
+    Unsolved,
+    MissingInput(PathBuf),
+    Error(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Unsolved => write!(f, "not solved yet"),
+            Self::MissingInput(path) => write!(f, "input file {} not found", path.display()),
+            Self::Error(msg) => f.write_str(msg),
+        }
+    }
+}
+
+/// Solves `input`, turning errors and panics into a `Failure`.
+pub fn solve_guarded(puzzle: &Puzzle, args: &Args, input: &Input) -> Result<Answers, Failure> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        puzzle.create(args)?.solve(input.open()?)
+    }));
+    match result {
+        Ok(result) => result.map_err(|err| Failure::Error(err.to_string())),
+        Err(payload) => {
+            let msg = payload
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            if msg.starts_with("not yet implemented") || msg.starts_with("not implemented") {
+                Err(Failure::Unsolved)
+            } else {
+                Err(Failure::Error(format!("panicked: {}", msg)))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub puzzle: Puzzle,
+    pub result: Result<Answers, Failure>,
+    pub time: Duration,
+}
+
+/// Finds the input a puzzle runs on in run-all mode: `dayNN-input`, or the
+/// sample `--sample` would pick when `sample` is set.
+pub fn discover_input(root: &Path, puzzle: &Puzzle, sample: bool) -> Result<PathBuf, Failure> {
+    let default = data_dir(root, puzzle.year).join(if sample {
+        format!("day{:02}-sample", puzzle.number)
+    } else {
+        format!("day{:02}-input", puzzle.number)
+    });
+    let input = if sample {
+        Input::sample(root, puzzle, "").ok()
+    } else {
+        Some(Input::File(default.clone()))
+    };
+    match input {
+        Some(Input::File(path)) if path.is_file() => Ok(path),
+        _ => Err(Failure::MissingInput(default)),
+    }
+}
+
+/// Runs a puzzle on its discovered input, taking its parameters from the
+/// input's `.answers` file if there is one.
+pub fn run_discovered(root: &Path, puzzle: &Puzzle, sample: bool) -> DayRun {
+    let start_time = Instant::now();
+    let result = discover_input(root, puzzle, sample).and_then(|path| {
+        let args = match Expected::load(&path) {
+            Ok(Some(expected)) => expected.args(puzzle, &path, None),
+            Ok(None) => Expected::default().args(puzzle, &path, None),
+            Err(err) => Err(err),
+        }
+        .map_err(|err| Failure::Error(err.to_string()))?;
+        solve_guarded(puzzle, &args, &Input::File(path))
+    });
+    let result = match result {
+        Err(Failure::Error(_)) if matches!(puzzle.parts, Parts::Unsolved) => Err(Failure::Unsolved),
+        result => result,
+    };
+    DayRun {
+        puzzle: *puzzle,
+        result,
+        time: start_time.elapsed(),
+    }
+}
+
+/// Runs puzzles on `jobs` threads, returning their runs in the original order.
+pub fn run_all<'a>(
+    puzzles: impl IntoIterator<Item = &'a Puzzle>,
+    root: &Path,
+    sample: bool,
+    jobs: usize,
+) -> Vec<DayRun> {
+    let puzzles = puzzles.into_iter().collect_vec();
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(puzzles.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, puzzles.len().max(1)) {
+            // Match the main thread's stack, some solutions recurse deeply.
+            thread::Builder::new()
+                .stack_size(8 << 20)
+                .spawn_scoped(scope, || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(puzzle) = puzzles.get(i) else {
+                        break;
+                    };
+                    let run = run_discovered(root, puzzle, sample);
+                    runs.lock().unwrap().push((i, run));
+                })
+                .expect("failed to spawn worker thread");
+        }
+    });
+    runs.into_inner()
+        .unwrap()
+        .into_iter()
+        .sorted_by_key(|(i, _)| *i)
+        .map(|(_, run)| run)
+        .collect()
 }
@@ -0,0 +1,175 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter,
+};
+
+use nom::error::ErrorKind;
+
+use crate::line_stream::describe_error_kind;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// A nom parser failed at the 1-based `line` and `col` of the input.
+    Parse {
+        line: usize,
+        col: usize,
+        kind: ErrorKind,
+        text: String,
+    },
+    /// The input is well-formed but doesn't describe a valid puzzle.
+    InvalidInput(String),
+    /// The input is valid but the solution can't find an answer for it.
+    Unsolvable(String),
+    /// The solution needs a cargo feature that wasn't enabled.
+    MissingFeature(&'static str),
+}
+
+impl AocError {
+    pub fn invalid_input(msg: impl Into<String>) -> Self {
+        Self::InvalidInput(msg.into())
+    }
+
+    pub fn unsolvable(msg: impl Into<String>) -> Self {
+        Self::Unsolvable(msg.into())
+    }
+
+    /// Converts the byte offset of a nom error within `text` to a parse error.
+    pub fn from_nom(line: usize, text: &str, error: &nom::error::Error<usize>) -> Self {
+        let col = text
+            .get(..error.input)
+            .map_or(error.input, |prefix| prefix.chars().count());
+        Self::Parse {
+            line,
+            col: col + 1,
+            kind: error.code,
+            text: text.to_owned(),
+        }
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::Parse { .. } => 3,
+            Self::InvalidInput(_) => 4,
+            Self::Unsolvable(_) => 5,
+            Self::MissingFeature(_) => 6,
+        }
+    }
+
+    /// Finds the `AocError` in the source chain of `error`, if any.
+    pub fn find<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a Self> {
+        let mut error = Some(error);
+        while let Some(current) = error {
+            if let Some(aoc_error) = current.downcast_ref::<Self>() {
+                return Some(aoc_error);
+            }
+            error = current.source();
+        }
+        None
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Parse {
+                line,
+                col,
+                kind,
+                text,
+            } => {
+                let gutter = line.to_string().len();
+                // Keeps the tabs before the error so that the caret lines up.
+                let indent = text
+                    .chars()
+                    .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+                    .chain(iter::repeat(' '))
+                    .take(col - 1)
+                    .collect::<String>();
+                writeln!(
+                    f,
+                    "line {}, column {}: {} ({:?})",
+                    line,
+                    col,
+                    describe_error_kind(*kind),
+                    kind
+                )?;
+                writeln!(f, "{} | {}", line, text)?;
+                write!(f, "{:gutter$} | {}^", "", indent, gutter = gutter)
+            }
+            Self::InvalidInput(msg) => write!(f, "invalid input: {}", msg),
+            Self::Unsolvable(msg) => write!(f, "unsolvable: {}", msg),
+            Self::MissingFeature(name) => {
+                write!(f, "this solution requires the \"{}\" feature", name)
+            }
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(text: &str, offset: usize) -> AocError {
+        AocError::from_nom(12, text, &nom::error::Error::new(offset, ErrorKind::Digit))
+    }
+
+    #[test]
+    fn points_at_the_column() {
+        assert_eq!(
+            parse_error("move x from 2", 5).to_string(),
+            "line 12, column 6: expected a digit (Digit)\n12 | move x from 2\n   |      ^"
+        );
+        assert_eq!(
+            parse_error("move", 4).to_string().lines().last(),
+            Some("   |     ^")
+        );
+    }
+
+    #[test]
+    fn counts_characters_and_keeps_tabs() {
+        // "é" takes two bytes, so the byte offset 7 is the 6th character.
+        let error = parse_error("\tcafé x", 7);
+        assert!(matches!(error, AocError::Parse { col: 7, .. }));
+        assert_eq!(
+            error.to_string().lines().skip(1).collect::<Vec<_>>(),
+            ["12 | \tcafé x", "   | \t     ^"]
+        );
+    }
+
+    #[test]
+    fn maps_exit_codes() {
+        assert_eq!(parse_error("x", 0).exit_code(), 3);
+        assert_eq!(AocError::invalid_input("no start").exit_code(), 4);
+        assert_eq!(AocError::unsolvable("no path").exit_code(), 5);
+        assert_eq!(AocError::MissingFeature("z3").exit_code(), 6);
+    }
+
+    #[test]
+    fn finds_the_error_in_the_source_chain() {
+        #[derive(Debug)]
+        struct Wrapper(AocError);
+
+        impl Display for Wrapper {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, "wrapped: {}", self.0)
+            }
+        }
+
+        impl Error for Wrapper {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let error: Box<dyn Error> = Box::new(Wrapper(AocError::unsolvable("no path")));
+        assert_eq!(
+            AocError::find(error.as_ref()).map(AocError::exit_code),
+            Some(5)
+        );
+        let error: Box<dyn Error> = "plain".into();
+        assert!(AocError::find(error.as_ref()).is_none());
+    }
+}
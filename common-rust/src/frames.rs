@@ -0,0 +1,546 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Write as _,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{report::json_string, terrain::Terrain};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+
+    /// Black or white, whichever reads better on top of this colour.
+    pub fn contrasting(self) -> Self {
+        let luma = 299 * self.0 as u32 + 587 * self.1 as u32 + 114 * self.2 as u32;
+        if luma > 128_000 {
+            Self::BLACK
+        } else {
+            Self::WHITE
+        }
+    }
+
+    /// Blends towards `other`, from this colour at 0 to `other` at 1.
+    pub fn mix(self, other: Self, ratio: f64) -> Self {
+        let ratio = ratio.clamp(0.0, 1.0);
+        let channel =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * ratio).round() as u8;
+        Self(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    /// The nearest colour of the 6×6×6 cube used when a GIF needs more than
+    /// 256 colours.
+    fn quantized(self) -> Self {
+        let level = |c: u8| (c as u16 * 5 + 127) / 255 * 51;
+        Self(
+            level(self.0) as u8,
+            level(self.1) as u8,
+            level(self.2) as u8,
+        )
+    }
+}
+
+/// A snapshot of a terrain, one glyph and colour per cell.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<(char, Rgb)>,
+}
+
+impl Frame {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cell(&self, x: usize, y: usize) -> (char, Rgb) {
+        self.cells[y * self.width + x]
+    }
+
+    /// Writes a binary (P6) PPM with every cell drawn as a `scale`-sized
+    /// square.
+    pub fn write_ppm(&self, out: &mut dyn Write, scale: usize) -> io::Result<()> {
+        write!(
+            out,
+            "P6\n{} {}\n255\n",
+            self.width * scale,
+            self.height * scale
+        )?;
+        let mut row = Vec::with_capacity(self.width * scale * 3);
+        for y in 0..self.height {
+            row.clear();
+            for x in 0..self.width {
+                let (_, Rgb(r, g, b)) = self.cell(x, y);
+                for _ in 0..scale {
+                    row.extend_from_slice(&[r, g, b]);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_ansi(&self, out: &mut String) {
+        let mut current = None;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (glyph, color) = self.cell(x, y);
+                if current != Some(color) {
+                    let Rgb(fr, fg, fb) = color.contrasting();
+                    let Rgb(br, bg, bb) = color;
+                    let _ = write!(
+                        out,
+                        "\x1B[38;2;{};{};{};48;2;{};{};{}m",
+                        fr, fg, fb, br, bg, bb
+                    );
+                    current = Some(color);
+                }
+                out.push(glyph);
+            }
+            out.push_str("\x1B[m\r\n");
+            current = None;
+        }
+    }
+}
+
+/// Captures successive snapshots of a `Terrain` simulation for playback as an
+/// asciicast, a sequence of PPM images or an animated GIF.
+pub struct FrameRecorder<T> {
+    color: Box<dyn Fn(&T) -> Rgb>,
+    glyph: Box<dyn Fn(&T) -> char>,
+    scale: usize,
+    delay: Duration,
+    frames: Vec<Frame>,
+}
+
+impl<T> FrameRecorder<T> {
+    pub fn new(color: impl Fn(&T) -> Rgb + 'static) -> Self {
+        Self {
+            color: Box::new(color),
+            glyph: Box::new(|_| ' '),
+            scale: 4,
+            delay: Duration::from_millis(100),
+            frames: Vec::new(),
+        }
+    }
+
+    /// Sets the character shown for a cell in the asciicast, a blank by
+    /// default.
+    pub fn with_glyph(mut self, glyph: impl Fn(&T) -> char + 'static) -> Self {
+        self.glyph = Box::new(glyph);
+        self
+    }
+
+    /// Sets the size in pixels of a cell in the exported images.
+    pub fn with_scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn record(&mut self, terrain: &Terrain<T>) {
+        let (width, height) = (terrain.width(), terrain.height());
+        let cells = terrain
+            .iter()
+            .map(|cell| ((self.glyph)(cell), (self.color)(cell)))
+            .collect();
+        self.frames.push(Frame {
+            width,
+            height,
+            cells,
+        });
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    fn screen_size(&self) -> (usize, usize) {
+        self.frames.iter().fold((0, 0), |(width, height), frame| {
+            (width.max(frame.width), height.max(frame.height))
+        })
+    }
+
+    /// Writes an asciicast v2 recording, redrawing the whole screen on each
+    /// frame.
+    pub fn write_asciicast(&self, out: &mut dyn Write) -> io::Result<()> {
+        let (width, height) = self.screen_size();
+        writeln!(
+            out,
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+            width,
+            height + 1
+        )?;
+        let mut screen = String::new();
+        for (i, frame) in self.frames.iter().enumerate() {
+            screen.clear();
+            screen.push_str("\x1B[H\x1B[2J");
+            frame.write_ansi(&mut screen);
+            writeln!(
+                out,
+                "[{:.6}, \"o\", {}]",
+                (self.delay * i as u32).as_secs_f64(),
+                json_string(&screen)
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes one `frameNNNNN.ppm` per frame into `dir`, returning their paths.
+    pub fn write_ppm_sequence(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        let mut paths = Vec::with_capacity(self.frames.len());
+        for (i, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame{:05}.ppm", i));
+            let mut out = BufWriter::new(File::create(&path)?);
+            frame.write_ppm(&mut out, self.scale)?;
+            out.flush()?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Writes a looping GIF89a. Frames smaller than the largest one are padded
+    /// with black, and colours are reduced to a 6×6×6 cube when there are more
+    /// than 256 of them.
+    pub fn write_gif(&self, out: &mut dyn Write) -> io::Result<()> {
+        let (width, height) = self.screen_size();
+        let (screen_width, screen_height) = (width * self.scale, height * self.scale);
+        if screen_width > u16::MAX as usize || screen_height > u16::MAX as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frames are too large for a GIF",
+            ));
+        }
+
+        let mut colors: Vec<Rgb> = vec![Rgb::BLACK];
+        let mut indices: HashMap<Rgb, u8> = HashMap::new();
+        let mut quantize = false;
+        for (_, color) in self.frames.iter().flat_map(|frame| &frame.cells) {
+            if !colors.contains(color) {
+                colors.push(*color);
+                if colors.len() > 256 {
+                    quantize = true;
+                    break;
+                }
+            }
+        }
+        if quantize {
+            colors = (0..216)
+                .map(|i| {
+                    Rgb(
+                        (i / 36 * 51) as u8,
+                        (i / 6 % 6 * 51) as u8,
+                        (i % 6 * 51) as u8,
+                    )
+                })
+                .collect();
+        }
+        for (i, color) in colors.iter().enumerate() {
+            indices.insert(*color, i as u8);
+        }
+        let table_bits = (usize::BITS - (colors.len() - 1).max(1).leading_zeros()).max(1);
+        colors.resize(1 << table_bits, Rgb::BLACK);
+        let index = |color: Rgb| {
+            if quantize {
+                indices[&color.quantized()]
+            } else {
+                indices[&color]
+            }
+        };
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&(screen_width as u16).to_le_bytes())?;
+        out.write_all(&(screen_height as u16).to_le_bytes())?;
+        out.write_all(&[0x80 | 0x70 | (table_bits as u8 - 1), 0, 0])?;
+        for Rgb(r, g, b) in &colors {
+            out.write_all(&[*r, *g, *b])?;
+        }
+        // Loop forever.
+        out.write_all(b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        let delay = (self.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        let min_code_size = table_bits.max(2) as u8;
+        let mut pixels = Vec::with_capacity(screen_width * screen_height);
+        for frame in &self.frames {
+            out.write_all(&[0x21, 0xF9, 0x04, 0x00])?;
+            out.write_all(&delay.to_le_bytes())?;
+            out.write_all(&[0x00, 0x00])?;
+
+            out.write_all(&[0x2C, 0, 0, 0, 0])?;
+            out.write_all(&(screen_width as u16).to_le_bytes())?;
+            out.write_all(&(screen_height as u16).to_le_bytes())?;
+            out.write_all(&[0x00])?;
+
+            pixels.clear();
+            for y in 0..screen_height {
+                for x in 0..screen_width {
+                    let (x, y) = (x / self.scale, y / self.scale);
+                    pixels.push(if x < frame.width && y < frame.height {
+                        index(frame.cell(x, y).1)
+                    } else {
+                        index(Rgb::BLACK)
+                    });
+                }
+            }
+            out.write_all(&[min_code_size])?;
+            for block in lzw_encode(&pixels, min_code_size).chunks(255) {
+                out.write_all(&[block.len() as u8])?;
+                out.write_all(block)?;
+            }
+            out.write_all(&[0x00])?;
+        }
+        out.write_all(&[0x3B])
+    }
+
+    /// Saves the recording in the format given by the extension of `path`:
+    /// `.cast` for asciicast, `.gif`, or none for a directory of PPM images.
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("cast") => {
+                let mut out = BufWriter::new(File::create(path)?);
+                self.write_asciicast(&mut out)?;
+                out.flush()?;
+            }
+            Some("gif") => {
+                let mut out = BufWriter::new(File::create(path)?);
+                self.write_gif(&mut out)?;
+                out.flush()?;
+            }
+            None => {
+                self.write_ppm_sequence(path)?;
+            }
+            Some(ext) => return Err(format!("unknown recording format .{}", ext).into()),
+        }
+        Ok(())
+    }
+}
+
+/// Variable-width LZW as used by GIF, emitting a clear code whenever the
+/// 12-bit code table fills up.
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut code_size = min_code_size + 1;
+    let mut next_code = end + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0u8;
+    let mut emit = |code: u16, size: u8| {
+        buffer |= (code as u32) << bits;
+        bits += size;
+        while bits >= 8 {
+            bytes.push(buffer as u8);
+            buffer >>= 8;
+            bits -= 8;
+        }
+    };
+
+    emit(clear, code_size);
+    let mut prefix: Option<u16> = None;
+    for &index in indices {
+        let Some(current) = prefix else {
+            prefix = Some(index as u16);
+            continue;
+        };
+        if let Some(&code) = table.get(&(current, index)) {
+            prefix = Some(code);
+            continue;
+        }
+        emit(current, code_size);
+        table.insert((current, index), next_code);
+        next_code += 1;
+        if next_code == 4096 {
+            emit(clear, code_size);
+            table.clear();
+            code_size = min_code_size + 1;
+            next_code = end + 1;
+        } else if next_code > 1 << code_size {
+            code_size += 1;
+        }
+        prefix = Some(index as u16);
+    }
+    if let Some(current) = prefix {
+        emit(current, code_size);
+    }
+    emit(end, code_size);
+    // Pads the last partial byte.
+    emit(0, 7);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A plain GIF LZW decoder, to check the encoder against.
+    fn lzw_decode(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let reset = || (0..clear).map(|i| vec![i as u8]).chain([vec![], vec![]]);
+        let mut table: Vec<Vec<u8>> = reset().collect();
+        let mut code_size = min_code_size + 1;
+        let (mut buffer, mut bits, mut bytes) = (0u32, 0u8, bytes.iter());
+        let mut previous: Option<usize> = None;
+        let mut indices = Vec::new();
+        loop {
+            while bits < code_size {
+                buffer |= (*bytes.next().expect("no end code") as u32) << bits;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as usize;
+            buffer >>= code_size;
+            bits -= code_size;
+
+            if code == clear {
+                table = reset().collect();
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return indices;
+            }
+            let entry = match (table.get(code), previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) if code == table.len() => {
+                    let mut entry = table[previous].clone();
+                    entry.push(entry[0]);
+                    entry
+                }
+                _ => panic!("code {} out of the table", code),
+            };
+            indices.extend_from_slice(&entry);
+            if let Some(previous) = previous {
+                let mut added = table[previous].clone();
+                added.push(entry[0]);
+                table.push(added);
+                if table.len() == 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            previous = Some(code);
+        }
+    }
+
+    fn recorder(scale: usize) -> FrameRecorder<u8> {
+        let palette = [Rgb(255, 0, 0), Rgb(0, 0, 255), Rgb(0, 255, 0)];
+        FrameRecorder::new(move |cell: &u8| palette[*cell as usize])
+            .with_glyph(|cell| ['"', '\\', 'x'][*cell as usize])
+            .with_scale(scale)
+    }
+
+    #[test]
+    fn encodes_lzw() {
+        let short = [0, 1, 0, 1, 0, 1, 0, 1, 2, 3, 3, 3, 3, 3, 3, 1];
+        assert_eq!(lzw_decode(&lzw_encode(&short, 2), 2), short);
+
+        // Long enough to widen the codes up to 12 bits and clear the table.
+        let mut seed = 1u32;
+        let long = (0..100_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (seed >> 16) as u8 % 5
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lzw_decode(&lzw_encode(&long, 3), 3), long);
+    }
+
+    #[test]
+    fn writes_gif() {
+        let mut recorder = recorder(1);
+        recorder.record(&Terrain::new_with(2, 2, |pt| (pt.0 ^ pt.1) as u8));
+        recorder.record(&Terrain::new_with(2, 2, |_| 2));
+        let mut gif = Vec::new();
+        recorder.write_gif(&mut gif).unwrap();
+
+        assert_eq!(&gif[..13], b"GIF89a\x02\x00\x02\x00\xF1\x00\x00");
+        assert_eq!(
+            &gif[13..25],
+            [0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 255, 0].as_slice()
+        );
+        assert_eq!(&gif[25..44], b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+        let mut rest = &gif[44..];
+        for expected in [[1, 2, 2, 1], [3, 3, 3, 3]] {
+            assert_eq!(rest[..8], [0x21, 0xF9, 0x04, 0x00, 10, 0, 0, 0]);
+            assert_eq!(rest[8..18], [0x2C, 0, 0, 0, 0, 2, 0, 2, 0, 0]);
+            let min_code_size = rest[18];
+            rest = &rest[19..];
+            let mut data = Vec::new();
+            while rest[0] != 0 {
+                let len = rest[0] as usize;
+                data.extend_from_slice(&rest[1..=len]);
+                rest = &rest[len + 1..];
+            }
+            rest = &rest[1..];
+            assert_eq!(lzw_decode(&data, min_code_size), expected);
+        }
+        assert_eq!(rest, [0x3B]);
+    }
+
+    #[test]
+    fn writes_scaled_ppm() {
+        let mut recorder = recorder(2);
+        recorder.record(&Terrain::new_with(2, 1, |pt| pt.0 as u8));
+        let mut ppm = Vec::new();
+        recorder.frames()[0].write_ppm(&mut ppm, 2).unwrap();
+
+        let row = [255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255];
+        assert_eq!(&ppm[..11], b"P6\n4 2\n255\n");
+        assert_eq!(ppm[11..], [row, row].concat());
+    }
+
+    #[test]
+    fn writes_asciicast() {
+        let mut recorder = recorder(1);
+        recorder.record(&Terrain::new_with(2, 1, |pt| pt.0 as u8));
+        recorder.record(&Terrain::new_with(1, 1, |_| 2));
+        let mut cast = Vec::new();
+        recorder.write_asciicast(&mut cast).unwrap();
+
+        let cast = String::from_utf8(cast).unwrap();
+        let lines = cast.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], r#"{"version": 2, "width": 2, "height": 2}"#);
+        assert_eq!(
+            lines[1],
+            concat!(
+                r#"[0.000000, "o", "\u001b[H\u001b[2J"#,
+                r#"\u001b[38;2;255;255;255;48;2;255;0;0m\""#,
+                r#"\u001b[38;2;255;255;255;48;2;0;0;255m\\\u001b[m\r\n"]"#
+            )
+        );
+        assert!(lines[2].starts_with(r#"[0.100000, "o", "#));
+        assert_eq!(lines.len(), 3);
+    }
+}
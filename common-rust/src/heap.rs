@@ -0,0 +1,137 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering},
+};
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting what goes through it once `start_counting`
+/// was called. Live bytes are counted from that point on, so heap allocated
+/// before doesn't show up in the peak. To be installed by the binary:
+///
+/// ```text
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    if COUNTING.load(Ordering::Relaxed) {
+        let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn record_dealloc(size: usize) {
+    if COUNTING.load(Ordering::Relaxed) {
+        LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// What a piece of code did with the heap, all zeros unless the
+/// `CountingAllocator` is installed.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct HeapStats {
+    /// The most bytes live at once on top of those live when it started, so
+    /// zero if the code only freed memory.
+    pub peak: usize,
+    pub allocated: usize,
+    pub allocations: usize,
+}
+
+impl Display for HeapStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "peak {}, {} allocated in {} allocations",
+            Bytes(self.peak),
+            Bytes(self.allocated),
+            self.allocations
+        )
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 MiB`.
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit + 1 < UNITS.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.2} {}", value, UNITS[unit])
+    }
+}
+
+pub fn start_counting() {
+    COUNTING.store(true, Ordering::Relaxed);
+}
+
+/// Runs `f`, counting its allocations. Meant for one thread at a time, as
+/// allocations from other threads are counted too.
+///
+/// The live bytes are only known relative to `start_counting`, and go
+/// negative when memory allocated before is freed, so the peak is measured
+/// from what is live when `f` starts.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, HeapStats) {
+    start_counting();
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = HeapStats {
+        peak: (PEAK.load(Ordering::Relaxed) - live).max(0) as usize,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    };
+    (result, stats)
+}
@@ -0,0 +1,142 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    day::Puzzle,
+    verify::{data_dir, inputs},
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    /// Resolves `--sample [name]` to `YYYY-data/dayNN-sample[name]`, also
+    /// accepting `dayNN-sample-[name]` (e.g. `--sample gold`). Without a name,
+    /// days that only have numbered samples fall back to the first of them.
+    pub fn sample(root: &Path, puzzle: &Puzzle, name: &str) -> Result<Self, Box<dyn Error>> {
+        let dir = data_dir(root, puzzle.year);
+        let mut candidates = vec![dir.join(format!("day{:02}-sample{}", puzzle.number, name))];
+        if !name.is_empty() {
+            candidates.push(dir.join(format!("day{:02}-sample-{}", puzzle.number, name)));
+        }
+        if let Some(path) = candidates.iter().find(|path| path.is_file()) {
+            return Ok(Self::File(path.clone()));
+        }
+        if name.is_empty() {
+            if let Some(path) = inputs(root, puzzle)?
+                .into_iter()
+                .find(|path| is_sample(path))
+            {
+                return Ok(Self::File(path));
+            }
+        }
+        Err(format!("sample file {} not found", candidates[0].display()).into())
+    }
+
+    /// The git-ignored `YYYY-data/dayNN-input`, read unless another input is
+    /// given; stdin is only read when asked for explicitly.
+    pub fn discover(root: &Path, puzzle: &Puzzle) -> Self {
+        Self::File(data_dir(root, puzzle.year).join(format!("day{:02}-input", puzzle.number)))
+    }
+
+    pub fn is_sample(&self) -> bool {
+        match self {
+            Self::Stdin => false,
+            Self::File(path) => is_sample(path),
+        }
+    }
+
+    pub fn open(&self) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+        match self {
+            Self::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            Self::File(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    Err(format!("input file {} not found", path.display()).into())
+                }
+                Err(err) => {
+                    Err(format!("cannot open input file {}: {}", path.display(), err).into())
+                }
+            },
+        }
+    }
+}
+
+/// Whether a data file is one of the `dayNN-sample*` files.
+pub fn is_sample(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_prefix("day"))
+        .and_then(|name| name.split_once('-'))
+        .is_some_and(|(_, rest)| rest.starts_with("sample"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::*;
+    use crate::day::Parts;
+
+    const PUZZLE: Puzzle = Puzzle::new(
+        2023,
+        12,
+        "Hot Springs",
+        Parts::Selectable,
+        |_| unreachable!(),
+    );
+
+    fn temp_root(name: &str, files: &[&str]) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-input-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(data_dir(&root, PUZZLE.year)).unwrap();
+        for file in files {
+            fs::write(data_dir(&root, PUZZLE.year).join(file), "").unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn finds_named_samples() {
+        let root = temp_root(
+            "named",
+            &["day12-sample", "day12-sample-gold", "day12-sample2"],
+        );
+        let dir = data_dir(&root, PUZZLE.year);
+        let sample = |name| Input::sample(&root, &PUZZLE, name).unwrap();
+        assert_eq!(sample(""), Input::File(dir.join("day12-sample")));
+        assert_eq!(sample("gold"), Input::File(dir.join("day12-sample-gold")));
+        assert_eq!(sample("2"), Input::File(dir.join("day12-sample2")));
+        assert!(Input::sample(&root, &PUZZLE, "3").is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_the_first_sample() {
+        let root = temp_root(
+            "numbered",
+            &[
+                "day12-sample2",
+                "day12-sample1",
+                "day12-sample1.answers",
+                "day11-sample",
+            ],
+        );
+        assert_eq!(
+            Input::sample(&root, &PUZZLE, "").unwrap(),
+            Input::File(data_dir(&root, PUZZLE.year).join("day12-sample1"))
+        );
+        fs::remove_dir_all(&root).unwrap();
+
+        let root = temp_root("none", &["day12-input"]);
+        assert!(Input::sample(&root, &PUZZLE, "").is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
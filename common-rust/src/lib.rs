@@ -1,16 +1,29 @@
+pub mod answers;
+pub mod bench;
 pub mod bit_matrix;
 pub mod cc;
 pub mod day;
 pub mod digit;
+pub mod error;
+pub mod frames;
+pub mod heap;
+pub mod input;
 pub mod line_stream;
+pub mod logging;
 pub mod mapping;
 pub mod math;
 pub mod mem;
 pub mod multi_range;
 pub mod option_flow;
 pub mod ord;
+pub mod parse;
 pub mod point;
+pub mod report;
+pub mod sample_tests;
+pub mod search;
+pub mod stepper;
 pub mod terrain;
+pub mod verify;
 
 pub fn unwrap_either<T>(result: Result<T, T>) -> T {
     match result {
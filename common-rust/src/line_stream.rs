@@ -1,12 +1,16 @@
 use std::array::TryFromSliceError;
 use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use nom::bytes::complete::take;
 use nom::combinator::{eof, map_res};
-use nom::error::{FromExternalError, ParseError};
+use nom::error::{ErrorKind, FromExternalError, ParseError};
 use nom::sequence::terminated;
 use nom::{Finish, Parser};
 
+use crate::answers::Answers;
+use crate::error::AocError;
+
 pub fn parse_full_string<'a, F>(
     s: &'a str,
     f: F,
@@ -23,6 +27,78 @@ where
     }
 }
 
+/// Describes what a nom parser was looking for when it failed with `kind`.
+pub fn describe_error_kind(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Tag => "unexpected text",
+        ErrorKind::Char => "unexpected character",
+        ErrorKind::OneOf => "expected one of a set of characters",
+        ErrorKind::NoneOf => "unexpected character",
+        ErrorKind::Digit | ErrorKind::HexDigit | ErrorKind::OctDigit | ErrorKind::BinDigit => {
+            "expected a digit"
+        }
+        ErrorKind::Float => "expected a number",
+        ErrorKind::Alpha => "expected a letter",
+        ErrorKind::AlphaNumeric => "expected a letter or a digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "expected whitespace",
+        ErrorKind::CrLf => "expected a line ending",
+        ErrorKind::Eof => "unexpected trailing input",
+        ErrorKind::Alt => "none of the alternatives matched",
+        ErrorKind::MapRes | ErrorKind::MapOpt => "invalid value",
+        ErrorKind::Verify => "value rejected",
+        ErrorKind::Count | ErrorKind::ManyMN | ErrorKind::Many1 | ErrorKind::Many1Count => {
+            "not enough repetitions"
+        }
+        ErrorKind::SeparatedList => "expected a separated list",
+        ErrorKind::TakeWhile1 | ErrorKind::TakeWhileMN | ErrorKind::TakeUntil => {
+            "expected more matching characters"
+        }
+        ErrorKind::Complete => "unexpected end of line",
+        _ => "parse error",
+    }
+}
+
+/// Attaches the line being processed to an error raised by a handler. nom
+/// errors are turned into `AocError::Parse` instead, see `line_error`.
+#[derive(Debug)]
+pub struct LineError {
+    pub number: usize,
+    pub line: String,
+    pub source: Box<dyn Error>,
+}
+
+impl LineError {
+    pub fn new(number: usize, line: &str, source: Box<dyn Error>) -> Self {
+        Self {
+            number,
+            line: line.to_owned(),
+            source,
+        }
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "line {}: {}", self.number, self.source)
+    }
+}
+
+impl Error for LineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+pub fn line_error(number: usize, line: &str, source: Box<dyn Error>) -> Box<dyn Error> {
+    if let Some(error) = source.downcast_ref::<nom::error::Error<usize>>() {
+        return AocError::from_nom(number, line, error).into();
+    }
+    if let Some(AocError::Parse { .. }) = source.downcast_ref::<AocError>() {
+        return source;
+    }
+    LineError::new(number, line, source).into()
+}
+
 pub fn take_fixed<'a, const N: usize, E>() -> impl Parser<&'a str, Output = [u8; N], Error = E>
 where
     E: ParseError<&'a str> + FromExternalError<&'a str, TryFromSliceError>,
@@ -32,7 +108,7 @@ where
 
 pub trait LineStreamHandler {
     fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>>;
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>>;
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>>;
 }
 
 pub trait LineStreamHandlerOnce {
@@ -40,7 +116,7 @@ pub trait LineStreamHandlerOnce {
         self: Box<Self>,
         line: &str,
     ) -> Result<Box<dyn LineStreamHandlerOnce>, Box<dyn Error>>;
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>>;
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>>;
 }
 
 struct WrappedLineStreamHandlerOnce(Option<Box<dyn LineStreamHandlerOnce>>);
@@ -58,7 +134,7 @@ impl LineStreamHandler for WrappedLineStreamHandlerOnce {
         }
     }
 
-    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
         self.0.unwrap().finish()
     }
 }
@@ -69,3 +145,177 @@ where
 {
     WrappedLineStreamHandlerOnce(Some(Box::new(handler)))
 }
+
+struct SplitPartsHandler {
+    silver: Box<dyn LineStreamHandler>,
+    gold: Box<dyn LineStreamHandler>,
+}
+
+impl LineStreamHandler for SplitPartsHandler {
+    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
+        self.silver.update(line)?;
+        self.gold.update(line)
+    }
+
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let silver = self.silver.finish()?;
+        let gold = self.gold.finish()?;
+        let mut answers = Answers {
+            silver: silver.silver,
+            gold: gold.gold,
+            extras: silver.extras,
+        };
+        for mut extra in gold.extras {
+            if answers.extras.contains(&extra) {
+                continue;
+            }
+            let silver_labels = answers.silver.iter().chain(&answers.extras);
+            if silver_labels
+                .map(|answer| &answer.label)
+                .any(|label| *label == extra.label)
+            {
+                extra.label.push_str(" (gold)");
+            }
+            answers.extras.push(extra);
+        }
+        Ok(answers)
+    }
+}
+
+/// Feeds every line to two handlers, taking the silver answer from the first
+/// one and the gold answer from the second one.
+pub fn split_parts(
+    silver: Box<dyn LineStreamHandler>,
+    gold: Box<dyn LineStreamHandler>,
+) -> impl LineStreamHandler {
+    SplitPartsHandler { silver, gold }
+}
+
+type Section<C> = Box<dyn FnMut(&mut C, &str) -> Result<(), Box<dyn Error>>>;
+type SectionsFinish<C> = Box<dyn FnOnce(C) -> Result<Answers, Box<dyn Error>>>;
+
+/// Handles an input made of sections separated by blank lines, each section
+/// updating a shared context with its own function. The blank lines moving to
+/// the next section aren't passed on, a run of them counting as one separator,
+/// while blank lines later in the last section go to that section.
+///
+/// ```text
+/// SectionedHandler::new(Day5::new(), Day5::finish)
+///     .with_section(Day5::add_ordering)
+///     .with_section(Day5::add_update)
+/// ```
+pub struct SectionedHandler<C> {
+    context: C,
+    sections: Vec<Section<C>>,
+    current: usize,
+    separated: bool,
+    finish: SectionsFinish<C>,
+}
+
+impl<C> SectionedHandler<C> {
+    pub fn new(
+        context: C,
+        finish: impl FnOnce(C) -> Result<Answers, Box<dyn Error>> + 'static,
+    ) -> Self {
+        Self {
+            context,
+            sections: Vec::new(),
+            current: 0,
+            separated: false,
+            finish: Box::new(finish),
+        }
+    }
+
+    pub fn with_section(
+        mut self,
+        section: impl FnMut(&mut C, &str) -> Result<(), Box<dyn Error>> + 'static,
+    ) -> Self {
+        self.sections.push(Box::new(section));
+        self
+    }
+
+    /// The index of the section the next line goes to.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+}
+
+impl<C> LineStreamHandler for SectionedHandler<C> {
+    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
+        if line.is_empty() && (self.separated || self.current + 1 < self.sections.len()) {
+            if !self.separated {
+                self.current += 1;
+                self.separated = true;
+            }
+            return Ok(());
+        }
+        self.separated = false;
+        match self.sections.get_mut(self.current) {
+            Some(section) => section(&mut self.context, line),
+            None => Err("no section to handle the input".into()),
+        }
+    }
+
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        (self.finish)(self.context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Lines = [Vec<String>; 2];
+
+    fn sectioned() -> SectionedHandler<Lines> {
+        fn add(section: usize) -> impl FnMut(&mut Lines, &str) -> Result<(), Box<dyn Error>> {
+            move |lines, line| {
+                lines[section].push(line.to_owned());
+                Ok(())
+            }
+        }
+
+        SectionedHandler::new(Lines::default(), |lines| {
+            Ok(Answers::new()
+                .with_silver("first", lines[0].join("|"))
+                .with_gold("second", lines[1].join("|")))
+        })
+        .with_section(add(0))
+        .with_section(add(1))
+    }
+
+    fn run(input: &str) -> (String, String) {
+        let mut handler = Box::new(sectioned());
+        for line in input.lines() {
+            handler.update(line).unwrap();
+        }
+        let answers = handler.finish().unwrap();
+        (
+            answers.silver.unwrap().value.to_string(),
+            answers.gold.unwrap().value.to_string(),
+        )
+    }
+
+    #[test]
+    fn switches_sections_on_a_blank_line() {
+        let mut handler = sectioned();
+        handler.update("a").unwrap();
+        assert_eq!(handler.current(), 0);
+        handler.update("").unwrap();
+        assert_eq!(handler.current(), 1);
+        assert_eq!(run("a\nb\n\nc\nd"), ("a|b".to_owned(), "c|d".to_owned()));
+    }
+
+    #[test]
+    fn passes_later_blank_lines_to_the_last_section() {
+        assert_eq!(run("a\n\nc\n"), ("a".to_owned(), "c".to_owned()));
+        assert_eq!(run("a\n\nc\n\n"), ("a".to_owned(), "c|".to_owned()));
+        assert_eq!(run("a\n\nc\n\nd"), ("a".to_owned(), "c||d".to_owned()));
+    }
+
+    #[test]
+    fn treats_blank_lines_in_a_row_as_one_separator() {
+        assert_eq!(run("a\n\n\nc"), ("a".to_owned(), "c".to_owned()));
+        assert_eq!(run("a\n\n\n\nc\nd"), ("a".to_owned(), "c|d".to_owned()));
+    }
+}
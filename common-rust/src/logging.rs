@@ -0,0 +1,196 @@
+use std::{
+    error::Error,
+    io::{self, Write},
+};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// A `log` backend writing to stderr, where records from a day's module (e.g.
+/// `aoc22_rs::day09::rope`) are attributed to that day (`2022/09`).
+///
+/// Filters pick the level of a day or module by prefix, the longest one
+/// winning:
+///
+/// ```text
+/// 2022/09=trace,aoc_common_rs=off
+/// ```
+#[derive(Clone, Debug)]
+pub struct Logger {
+    level: LevelFilter,
+    filters: Vec<(String, LevelFilter)>,
+}
+
+impl Logger {
+    pub fn new(level: LevelFilter) -> Self {
+        Self {
+            level,
+            filters: Vec::new(),
+        }
+    }
+
+    /// Maps the `-q`/`-v` command line flags to a level, warnings being shown
+    /// by default.
+    pub fn from_verbosity(quiet: bool, verbose: u8) -> Self {
+        Self::new(match (quiet, verbose) {
+            (true, _) => LevelFilter::Off,
+            (false, 0) => LevelFilter::Warn,
+            (false, 1) => LevelFilter::Info,
+            (false, 2) => LevelFilter::Debug,
+            (false, _) => LevelFilter::Trace,
+        })
+    }
+
+    pub fn with_filter(mut self, prefix: impl Into<String>, level: LevelFilter) -> Self {
+        self.filters.push((prefix.into(), level));
+        self
+    }
+
+    /// Adds the comma-separated `prefix=level` filters of `spec`.
+    pub fn with_filters(mut self, spec: &str) -> Result<Self, Box<dyn Error>> {
+        for filter in spec.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            let (prefix, level) = filter
+                .split_once('=')
+                .ok_or_else(|| format!("expected `target=level` in log filter {}", filter))?;
+            let level = level
+                .parse()
+                .map_err(|_| format!("unknown log level {}", level))?;
+            self = self.with_filter(prefix.trim(), level);
+        }
+        Ok(self)
+    }
+
+    pub fn max_level(&self) -> LevelFilter {
+        self.filters
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.level, Ord::max)
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        let label = day_label(target);
+        // A day label is as specific as the module path of the day it names.
+        let day_len = "aocYY_rs::dayNN".len();
+        self.filters
+            .iter()
+            .filter_map(|(prefix, level)| {
+                if label.as_deref() == Some(prefix.as_str()) {
+                    Some((day_len, *level))
+                } else {
+                    target
+                        .starts_with(prefix.as_str())
+                        .then_some((prefix.len(), *level))
+                }
+            })
+            .max_by_key(|(len, _)| *len)
+            .map_or(self.level, |(_, level)| level)
+    }
+
+    /// Installs the logger for the `log` macros.
+    pub fn init(self) -> Result<(), Box<dyn Error>> {
+        log::set_max_level(self.max_level());
+        log::set_boxed_logger(Box::new(self))?;
+        Ok(())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let target = record.target();
+        let label = day_label(target);
+        // One write per record keeps lines of parallel days from interleaving.
+        let message = format!(
+            "[{} {}] {}\n",
+            record.level().as_str().to_ascii_lowercase(),
+            label.as_deref().unwrap_or(target),
+            record.args()
+        );
+        let _ = io::stderr().lock().write_all(message.as_bytes());
+    }
+
+    fn flush(&self) {
+        let _ = io::stderr().flush();
+    }
+}
+
+/// Turns the module path of a day (`aocYY_rs::dayNN...`) into `YYYY/NN`.
+pub fn day_label(target: &str) -> Option<String> {
+    let mut path = target.split("::");
+    let year = path.next()?.strip_prefix("aoc")?.strip_suffix("_rs")?;
+    let day = path.next()?.strip_prefix("day")?;
+    if year.len() != 2
+        || day.len() != 2
+        || !year
+            .chars()
+            .chain(day.chars())
+            .all(|ch| ch.is_ascii_digit())
+    {
+        return None;
+    }
+    Some(format!("20{}/{}", year, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_day_modules() {
+        assert_eq!(day_label("aoc22_rs::day09"), Some("2022/09".to_owned()));
+        assert_eq!(
+            day_label("aoc24_rs::day16::maze"),
+            Some("2024/16".to_owned())
+        );
+        assert_eq!(day_label("aoc22_rs"), None);
+        assert_eq!(day_label("aoc22_rs::terrain"), None);
+        assert_eq!(day_label("aoc22_rs::day9"), None);
+        assert_eq!(day_label("aoc_common_rs::day"), None);
+        assert_eq!(day_label("aoc_cli_rs::client"), None);
+    }
+
+    #[test]
+    fn parses_filters() {
+        let logger = Logger::new(LevelFilter::Warn)
+            .with_filters(" 2022/09=trace, aoc_common_rs=off,,aoc_common_rs::bench=debug ")
+            .unwrap();
+        assert_eq!(
+            logger.filters,
+            [
+                ("2022/09".to_owned(), LevelFilter::Trace),
+                ("aoc_common_rs".to_owned(), LevelFilter::Off),
+                ("aoc_common_rs::bench".to_owned(), LevelFilter::Debug),
+            ]
+        );
+        assert_eq!(logger.max_level(), LevelFilter::Trace);
+        assert!(Logger::new(LevelFilter::Warn)
+            .with_filters("trace")
+            .is_err());
+        assert!(Logger::new(LevelFilter::Warn)
+            .with_filters("2022/09=loud")
+            .is_err());
+    }
+
+    #[test]
+    fn picks_the_longest_matching_filter() {
+        let logger = Logger::new(LevelFilter::Warn)
+            .with_filters(
+                "2022/09=trace,aoc22_rs=error,aoc_common_rs=off,aoc_common_rs::bench=debug",
+            )
+            .unwrap();
+        assert_eq!(
+            logger.level_for("aoc22_rs::day09::rope"),
+            LevelFilter::Trace
+        );
+        assert_eq!(logger.level_for("aoc22_rs::day10"), LevelFilter::Error);
+        assert_eq!(logger.level_for("aoc22_rs::terrain"), LevelFilter::Error);
+        assert_eq!(logger.level_for("aoc_common_rs::heap"), LevelFilter::Off);
+        assert_eq!(logger.level_for("aoc_common_rs::bench"), LevelFilter::Debug);
+        assert_eq!(logger.level_for("aoc_cli_rs::client"), LevelFilter::Warn);
+    }
+}
@@ -13,6 +13,10 @@ impl<T> Mapping<T> {
         self.indices_to_labels.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.indices_to_labels.is_empty()
+    }
+
     pub fn label(&self, index: usize) -> &T {
         &self.indices_to_labels[index]
     }
@@ -28,11 +32,7 @@ where
 {
     pub fn new(indices_to_labels: Vec<T>) -> Self {
         Self {
-            labels_to_indices: indices_to_labels
-                .iter()
-                .map(|label| label.clone())
-                .zip(0usize..)
-                .collect(),
+            labels_to_indices: indices_to_labels.iter().cloned().zip(0usize..).collect(),
             indices_to_labels,
         }
     }
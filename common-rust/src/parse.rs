@@ -0,0 +1,242 @@
+//! nom combinators for the input shapes coming back from one puzzle to the
+//! next. They all use `nom::error::Error<&str>`, like `parse_full_string`.
+
+use std::str::FromStr;
+
+use nom::bytes::complete::{tag, take_till1};
+use nom::character::complete::{char, digit1, one_of, satisfy, space0};
+use nom::combinator::{map, map_opt, map_res, opt, recognize};
+use nom::multi::{fold_many1, separated_list1};
+use nom::sequence::{preceded, separated_pair};
+use nom::Parser;
+use num_traits::PrimInt;
+
+use crate::cc::{FourCC, ThreeCC, TwoCC};
+use crate::line_stream::take_fixed;
+use crate::point::{Point2, Point3};
+
+type NomError<'a> = nom::error::Error<&'a str>;
+
+/// An optionally signed decimal integer.
+pub fn int<'a, T>() -> impl Parser<&'a str, Output = T, Error = NomError<'a>>
+where
+    T: FromStr,
+{
+    map_res(recognize((opt(one_of("+-")), digit1)), str::parse)
+}
+
+/// A comma-separated list, e.g. `1,2,3` or `a, b, c`.
+pub fn list<'a, F>(item: F) -> impl Parser<&'a str, Output = Vec<F::Output>, Error = NomError<'a>>
+where
+    F: Parser<&'a str, Error = NomError<'a>>,
+{
+    separated_list1((char(','), space0), item)
+}
+
+/// A value following a known label, e.g. `Register A: 729`.
+pub fn labelled<'a, F>(
+    label: &'a str,
+    value: F,
+) -> impl Parser<&'a str, Output = F::Output, Error = NomError<'a>>
+where
+    F: Parser<&'a str, Error = NomError<'a>>,
+{
+    preceded((tag(label), char(':'), space0), value)
+}
+
+/// A label and the list following it, e.g. `key: a, b, c`.
+pub fn labelled_list<'a, F>(
+    item: F,
+) -> impl Parser<&'a str, Output = (&'a str, Vec<F::Output>), Error = NomError<'a>>
+where
+    F: Parser<&'a str, Error = NomError<'a>>,
+{
+    separated_pair(take_till1(|ch| ch == ':'), (char(':'), space0), list(item))
+}
+
+/// An `x,y` pair of integers.
+pub fn point2<'a, T>() -> impl Parser<&'a str, Output = Point2<T>, Error = NomError<'a>>
+where
+    T: FromStr,
+{
+    map(
+        separated_pair(int(), (char(','), space0), int()),
+        |(x, y)| Point2(x, y),
+    )
+}
+
+/// An `x,y,z` triple of integers.
+pub fn point3<'a, T>() -> impl Parser<&'a str, Output = Point3<T>, Error = NomError<'a>>
+where
+    T: FromStr,
+{
+    map(
+        (int(), char(','), space0, int(), char(','), space0, int()),
+        |(x, _, _, y, _, _, z)| Point3(x, y, z),
+    )
+}
+
+/// A run of `on` and `off` characters, e.g. `.##.`, as a mask with the first
+/// character in the lowest bit, along with the length of the run. Fails when
+/// the run doesn't fit in `T`.
+pub fn bitmask<'a, T>(
+    on: char,
+    off: char,
+) -> impl Parser<&'a str, Output = (T, usize), Error = NomError<'a>>
+where
+    T: PrimInt,
+{
+    map_opt(
+        fold_many1(
+            satisfy(move |ch| ch == on || ch == off),
+            || Some((T::zero(), 0usize)),
+            move |acc, ch| {
+                let (mask, len) = acc?;
+                if len >= T::zero().count_zeros() as usize {
+                    return None;
+                }
+                Some((
+                    if ch == on {
+                        mask | T::one() << len
+                    } else {
+                        mask
+                    },
+                    len + 1,
+                ))
+            },
+        ),
+        |acc| acc,
+    )
+}
+
+pub fn two_cc<'a>() -> impl Parser<&'a str, Output = TwoCC, Error = NomError<'a>> {
+    map(take_fixed::<2, _>(), TwoCC::from)
+}
+
+pub fn three_cc<'a>() -> impl Parser<&'a str, Output = ThreeCC, Error = NomError<'a>> {
+    map(take_fixed::<3, _>(), ThreeCC::from)
+}
+
+pub fn four_cc<'a>() -> impl Parser<&'a str, Output = FourCC, Error = NomError<'a>> {
+    map(take_fixed::<4, _>(), FourCC::from)
+}
+
+/// An `a -> b` edge.
+pub fn edge<'a, F, G>(
+    from: F,
+    to: G,
+) -> impl Parser<&'a str, Output = (F::Output, G::Output), Error = NomError<'a>>
+where
+    F: Parser<&'a str, Error = NomError<'a>>,
+    G: Parser<&'a str, Error = NomError<'a>>,
+{
+    separated_pair(from, (space0, tag("->"), space0), to)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::alpha1;
+
+    use super::*;
+    use crate::line_stream::parse_full_string;
+
+    #[test]
+    fn parses_ints() {
+        assert_eq!(parse_full_string("42", int::<u8>()), Ok(42));
+        assert_eq!(parse_full_string("-42", int::<i64>()), Ok(-42));
+        assert_eq!(parse_full_string("+7", int::<i32>()), Ok(7));
+        assert!(parse_full_string("-1", int::<u32>()).is_err());
+        assert!(parse_full_string("256", int::<u8>()).is_err());
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!(
+            parse_full_string("3,-4", point2::<i32>()),
+            Ok(Point2(3, -4))
+        );
+        assert_eq!(parse_full_string("3, 4", point2::<u32>()), Ok(Point2(3, 4)));
+        assert_eq!(
+            parse_full_string("1,2,3", point3::<u16>()),
+            Ok(Point3(1, 2, 3))
+        );
+        assert!(parse_full_string("1,2", point3::<u16>()).is_err());
+    }
+
+    #[test]
+    fn parses_bitmasks() {
+        assert_eq!(
+            parse_full_string(".##.#", bitmask::<u8>('#', '.')),
+            Ok((0b10110, 5))
+        );
+        assert_eq!(
+            parse_full_string("########", bitmask::<u8>('#', '.')),
+            Ok((255, 8))
+        );
+        assert!(parse_full_string("#########", bitmask::<u8>('#', '.')).is_err());
+        assert!(parse_full_string("", bitmask::<u8>('#', '.')).is_err());
+        assert!(parse_full_string("#x", bitmask::<u8>('#', '.')).is_err());
+    }
+
+    #[test]
+    fn parses_ccs() {
+        assert_eq!(parse_full_string("rx", two_cc()), Ok(TwoCC::new('r', 'x')));
+        assert_eq!(
+            parse_full_string("z00", three_cc()),
+            Ok(ThreeCC::new('z', '0', '0'))
+        );
+        assert_eq!(
+            parse_full_string("abcd", four_cc()),
+            Ok(FourCC::new('a', 'b', 'c', 'd'))
+        );
+        assert!(parse_full_string("abc", two_cc()).is_err());
+        assert!(parse_full_string("ab", three_cc()).is_err());
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(
+            parse_full_string("0,1,5", list(int::<u8>())),
+            Ok(vec![0, 1, 5])
+        );
+        assert_eq!(
+            parse_full_string("Register A: 729", labelled("Register A", int::<u64>())),
+            Ok(729)
+        );
+        assert_eq!(
+            parse_full_string("Program: 0,3,5", labelled("Program", list(int::<u8>()))),
+            Ok(vec![0, 3, 5])
+        );
+        assert_eq!(
+            parse_full_string("key: a, bc, d", labelled_list(alpha1)),
+            Ok(("key", vec!["a", "bc", "d"]))
+        );
+        assert!(
+            parse_full_string("Register B: 729", labelled("Register A", int::<u64>())).is_err()
+        );
+        assert!(parse_full_string("key: ", labelled_list(alpha1)).is_err());
+    }
+
+    #[test]
+    fn parses_edges() {
+        assert_eq!(
+            parse_full_string("abc -> xyz", edge(three_cc(), three_cc())),
+            Ok((ThreeCC::new('a', 'b', 'c'), ThreeCC::new('x', 'y', 'z')))
+        );
+        assert_eq!(
+            parse_full_string("broadcaster -> a, b", edge(alpha1, list(two_cc_lax()))),
+            Ok((
+                "broadcaster",
+                vec![TwoCC::new('a', '\0'), TwoCC::new('b', '\0')]
+            ))
+        );
+        assert_eq!(
+            parse_full_string("1,2->3,4", edge(point2::<u8>(), point2::<u8>())),
+            Ok((Point2(1, 2), Point2(3, 4)))
+        );
+    }
+
+    fn two_cc_lax<'a>() -> impl Parser<&'a str, Output = TwoCC, Error = NomError<'a>> {
+        map(alpha1, TwoCC::from_lax)
+    }
+}
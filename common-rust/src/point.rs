@@ -165,6 +165,38 @@ where
     }
 }
 
+impl<T> Point2<T>
+where
+    T: Add<T, Output = T> + Copy + One + Sub<T, Output = T>,
+{
+    pub fn next_towards8(self, towards: Direction8) -> Self {
+        let mut next = self;
+        if let Some(horizontal) = towards.horizontal() {
+            next = next.next_towards(horizontal);
+        }
+        if let Some(vertical) = towards.vertical() {
+            next = next.next_towards(vertical);
+        }
+        next
+    }
+}
+
+impl<T> Point2<T>
+where
+    T: OverflowingAdd + Copy + One + OverflowingSub,
+{
+    pub fn try_next_towards8(self, towards: Direction8) -> Option<Self> {
+        let mut next = self;
+        if let Some(horizontal) = towards.horizontal() {
+            next = next.try_next_towards(horizontal)?;
+        }
+        if let Some(vertical) = towards.vertical() {
+            next = next.try_next_towards(vertical)?;
+        }
+        Some(next)
+    }
+}
+
 impl<T> Point2<T>
 where
     T: Add<T, Output = T> + Copy + One,
@@ -791,6 +823,115 @@ impl TryFrom<Direction3> for Direction2 {
     }
 }
 
+/// The four directions of `Direction2` and the diagonals between them, in
+/// clockwise order.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum Direction8 {
+    Right = 0,
+    DownRight = 1,
+    Down = 2,
+    DownLeft = 3,
+    Left = 4,
+    UpLeft = 5,
+    Up = 6,
+    UpRight = 7,
+}
+
+impl Direction8 {
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0u8..=7).map(|dir| Self::try_from(dir).unwrap())
+    }
+    pub fn diagonals() -> impl Iterator<Item = Self> {
+        Self::all().filter(|dir| dir.is_diagonal())
+    }
+    /// Rotates by 45°.
+    pub fn clockwise(self) -> Self {
+        Self::try_from((self as u8 + 1) % 8).unwrap()
+    }
+    /// Rotates by 45°.
+    pub fn counterclockwise(self) -> Self {
+        Self::try_from((self as u8 + 7) % 8).unwrap()
+    }
+    pub fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+    pub fn horizontal(self) -> Option<Direction2> {
+        match self {
+            Self::Right | Self::DownRight | Self::UpRight => Some(Direction2::Right),
+            Self::Left | Self::DownLeft | Self::UpLeft => Some(Direction2::Left),
+            Self::Down | Self::Up => None,
+        }
+    }
+    pub fn vertical(self) -> Option<Direction2> {
+        match self {
+            Self::Down | Self::DownRight | Self::DownLeft => Some(Direction2::Down),
+            Self::Up | Self::UpRight | Self::UpLeft => Some(Direction2::Up),
+            Self::Right | Self::Left => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for Direction8 {
+    type Error = Box<dyn Error>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Right),
+            1 => Ok(Self::DownRight),
+            2 => Ok(Self::Down),
+            3 => Ok(Self::DownLeft),
+            4 => Ok(Self::Left),
+            5 => Ok(Self::UpLeft),
+            6 => Ok(Self::Up),
+            7 => Ok(Self::UpRight),
+            _ => Err(Box::from("invalid direction")),
+        }
+    }
+}
+
+impl From<Direction2> for Direction8 {
+    fn from(value: Direction2) -> Self {
+        match value {
+            Direction2::Right => Self::Right,
+            Direction2::Down => Self::Down,
+            Direction2::Left => Self::Left,
+            Direction2::Up => Self::Up,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction2 {
+    type Error = Box<dyn Error>;
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        match value {
+            Direction8::Right => Ok(Self::Right),
+            Direction8::Down => Ok(Self::Down),
+            Direction8::Left => Ok(Self::Left),
+            Direction8::Up => Ok(Self::Up),
+            _ => Err(Box::from("not an orthogonal direction")),
+        }
+    }
+}
+
+impl<T> From<Direction8> for Point2<T>
+where
+    T: Signed + Copy,
+{
+    fn from(value: Direction8) -> Self {
+        Point2::zero().next_towards8(value)
+    }
+}
+
+impl Neg for Direction8 {
+    type Output = Direction8;
+
+    fn neg(self) -> Self::Output {
+        Self::try_from((self as u8 + 4) % 8).unwrap()
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[repr(u8)]
 pub enum Direction3 {
@@ -1015,3 +1156,40 @@ impl<T> IndexMut<Direction3> for Directional3<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_direction8() {
+        assert_eq!(Direction8::Right.clockwise(), Direction8::DownRight);
+        assert_eq!(Direction8::Right.counterclockwise(), Direction8::UpRight);
+        assert_eq!(-Direction8::DownLeft, Direction8::UpRight);
+        for dir in Direction8::all() {
+            assert_eq!(dir.clockwise().clockwise().clockwise().clockwise(), -dir);
+            assert_eq!(dir.clockwise().counterclockwise(), dir);
+        }
+        assert_eq!(Direction8::diagonals().count(), 4);
+    }
+
+    #[test]
+    fn converts_direction8() {
+        for dir in Direction2::all() {
+            let dir8 = Direction8::from(dir);
+            assert_eq!(Direction2::try_from(dir8).unwrap(), dir);
+            assert_eq!(Point2::<i32>::from(dir8), Point2::from(dir));
+            assert_eq!(
+                Direction8::from(dir.clockwise()),
+                dir8.clockwise().clockwise()
+            );
+        }
+        assert!(Direction2::try_from(Direction8::UpLeft).is_err());
+        assert_eq!(Point2::<i32>::from(Direction8::UpLeft), Point2(-1, -1));
+        assert_eq!(
+            Point2(3, 3).next_towards8(Direction8::DownLeft),
+            Point2(2, 4)
+        );
+        assert_eq!(Point2(0u8, 3).try_next_towards8(Direction8::UpLeft), None);
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Write as _,
+    io::{self, IsTerminal, Write},
+    str::FromStr,
+};
+
+use crate::{
+    answers::{Answer, Answers, Value},
+    day::{Puzzle, GOLD_ANSI, SILVER_ANSI},
+};
+
+pub trait Reporter {
+    fn report(&self, out: &mut dyn Write, puzzle: &Puzzle, answers: &Answers) -> io::Result<()>;
+}
+
+fn labelled(answers: &Answers) -> impl Iterator<Item = (Option<bool>, &Answer)> {
+    answers
+        .silver
+        .iter()
+        .map(|answer| (Some(false), answer))
+        .chain(answers.gold.iter().map(|answer| (Some(true), answer)))
+        .chain(answers.extras.iter().map(|answer| (None, answer)))
+}
+
+fn write_lines(
+    out: &mut dyn Write,
+    answers: &Answers,
+    silver: &str,
+    gold: &str,
+    extra: &str,
+) -> io::Result<()> {
+    let width = labelled(answers)
+        .map(|(_, answer)| answer.label.len())
+        .max()
+        .unwrap_or(0);
+    for (part, answer) in labelled(answers) {
+        let prefix = match part {
+            Some(false) => silver,
+            Some(true) => gold,
+            None => extra,
+        };
+        let value = answer.value.to_string();
+        if value.contains('\n') {
+            writeln!(out, "[{}] {}:", prefix, answer.label)?;
+            for line in value.lines() {
+                writeln!(out, "{}", line)?;
+            }
+        } else {
+            writeln!(
+                out,
+                "[{}] {:width$} {}",
+                prefix,
+                format!("{}:", answer.label),
+                value,
+                width = width + 1
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub struct AnsiReporter;
+
+impl Reporter for AnsiReporter {
+    fn report(&self, out: &mut dyn Write, _puzzle: &Puzzle, answers: &Answers) -> io::Result<()> {
+        write_lines(out, answers, SILVER_ANSI, GOLD_ANSI, "-")
+    }
+}
+
+pub struct PlainReporter;
+
+impl Reporter for PlainReporter {
+    fn report(&self, out: &mut dyn Write, _puzzle: &Puzzle, answers: &Answers) -> io::Result<()> {
+        write_lines(out, answers, "S", "G", "-")
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                write!(result, "\\u{:04x}", ch as u32).unwrap();
+            }
+            ch => result.push(ch),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Integer(value) => value.to_string(),
+        Value::Text(value) => json_string(value),
+    }
+}
+
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(answer) => format!(
+            "{{\"label\":{},\"value\":{}}}",
+            json_string(&answer.label),
+            json_value(&answer.value)
+        ),
+        None => "null".to_owned(),
+    }
+}
+
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn report(&self, out: &mut dyn Write, puzzle: &Puzzle, answers: &Answers) -> io::Result<()> {
+        let extras = answers
+            .extras
+            .iter()
+            .map(|answer| json_answer(Some(answer)))
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(
+            out,
+            "{{\"year\":{},\"day\":{},\"title\":{},\"silver\":{},\"gold\":{},\"extras\":[{}]}}",
+            puzzle.year,
+            puzzle.number,
+            json_string(puzzle.title),
+            json_answer(answers.silver.as_ref()),
+            json_answer(answers.gold.as_ref()),
+            extras
+        )
+    }
+}
+
+pub struct TapReporter;
+
+impl Reporter for TapReporter {
+    fn report(&self, out: &mut dyn Write, puzzle: &Puzzle, answers: &Answers) -> io::Result<()> {
+        let parts = [("silver", &answers.silver), ("gold", &answers.gold)];
+        writeln!(out, "TAP version 14")?;
+        writeln!(
+            out,
+            "1..{}",
+            parts.iter().filter(|(_, answer)| answer.is_some()).count()
+        )?;
+        writeln!(
+            out,
+            "# {} day {}: {}",
+            puzzle.year, puzzle.number, puzzle.title
+        )?;
+        let mut test = 0usize;
+        for (part, answer) in parts {
+            let Some(answer) = answer else {
+                continue;
+            };
+            test += 1;
+            let value = answer.value.to_string();
+            if value.contains('\n') {
+                writeln!(out, "ok {} - {}: {}", test, part, answer.label)?;
+                writeln!(out, "  ---")?;
+                writeln!(out, "  value: |")?;
+                for line in value.lines() {
+                    writeln!(out, "    {}", line)?;
+                }
+                writeln!(out, "  ...")?;
+            } else {
+                writeln!(out, "ok {} - {}: {} = {}", test, part, answer.label, value)?;
+            }
+        }
+        for answer in &answers.extras {
+            for line in format!("{}: {}", answer.label, answer.value).lines() {
+                writeln!(out, "# {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Format {
+    #[default]
+    Auto,
+    Ansi,
+    Plain,
+    Json,
+    Tap,
+}
+
+impl Format {
+    pub const NAMES: &'static [&'static str] = &["auto", "ansi", "plain", "json", "tap"];
+
+    pub fn resolve(self) -> Self {
+        match self {
+            Self::Auto => {
+                let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+                if !no_color && io::stdout().is_terminal() {
+                    Self::Ansi
+                } else {
+                    Self::Plain
+                }
+            }
+            format => format,
+        }
+    }
+
+    pub fn reporter(self) -> Box<dyn Reporter> {
+        match self.resolve() {
+            Self::Auto | Self::Plain => Box::new(PlainReporter),
+            Self::Ansi => Box::new(AnsiReporter),
+            Self::Json => Box::new(JsonReporter),
+            Self::Tap => Box::new(TapReporter),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "ansi" => Ok(Self::Ansi),
+            "plain" => Ok(Self::Plain),
+            "json" => Ok(Self::Json),
+            "tap" => Ok(Self::Tap),
+            _ => Err(format!("unknown output format {}", s).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day::Parts;
+
+    const PUZZLE: Puzzle = Puzzle::new(
+        2022,
+        10,
+        "Cathode-Ray Tube",
+        Parts::Both,
+        |_| unreachable!(),
+    );
+
+    fn answers() -> Answers {
+        Answers::new()
+            .with_silver("Signal strength", 13140)
+            .with_gold("Screen", "##..\n#..#")
+            .with_extra("Cycles", 240)
+    }
+
+    fn report(reporter: &dyn Reporter, answers: &Answers) -> String {
+        let mut out = Vec::new();
+        reporter.report(&mut out, &PUZZLE, answers).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn reports_plain() {
+        assert_eq!(
+            report(&PlainReporter, &answers()),
+            "[S] Signal strength: 13140\n[G] Screen:\n##..\n#..#\n[-] Cycles:          240\n"
+        );
+    }
+
+    #[test]
+    fn reports_ansi() {
+        let out = report(&AnsiReporter, &answers());
+        assert!(out.starts_with(&format!("[{}] Signal strength: 13140\n", SILVER_ANSI)));
+        assert!(out.contains(&format!("[{}] Screen:\n##..\n", GOLD_ANSI)));
+    }
+
+    #[test]
+    fn reports_json() {
+        assert_eq!(
+            report(&JsonReporter, &answers()),
+            concat!(
+                r#"{"year":2022,"day":10,"title":"Cathode-Ray Tube","#,
+                r#""silver":{"label":"Signal strength","value":13140},"#,
+                r###""gold":{"label":"Screen","value":"##..\n#..#"},"###,
+                r#""extras":[{"label":"Cycles","value":240}]}"#,
+                "\n"
+            )
+        );
+
+        let answers = Answers::new().with_silver("Text", "say \"hi\"\\\n\t\r\x01\x1f\u{e9}");
+        assert_eq!(
+            report(&JsonReporter, &answers),
+            concat!(
+                r#"{"year":2022,"day":10,"title":"Cathode-Ray Tube","#,
+                r#""silver":{"label":"Text","value":"say \"hi\"\\\n\t\r\u0001\u001f"#,
+                "\u{e9}",
+                r#""},"gold":null,"extras":[]}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn reports_tap() {
+        assert_eq!(
+            report(&TapReporter, &answers()),
+            concat!(
+                "TAP version 14\n",
+                "1..2\n",
+                "# 2022 day 10: Cathode-Ray Tube\n",
+                "ok 1 - silver: Signal strength = 13140\n",
+                "ok 2 - gold: Screen\n",
+                "  ---\n",
+                "  value: |\n",
+                "    ##..\n",
+                "    #..#\n",
+                "  ...\n",
+                "# Cycles: 240\n"
+            )
+        );
+        assert!(report(&TapReporter, &Answers::new()).starts_with("TAP version 14\n1..0\n"));
+    }
+}
@@ -0,0 +1,88 @@
+use std::{env, error::Error, fmt::Write as _, fs, path::Path};
+
+use crate::{
+    day::{Parts, Puzzle},
+    verify::{input_day, list_inputs, Expected},
+};
+
+/// Generates `$OUT_DIR/sample_tests.rs` with one test per sample file and
+/// expected part, meant to be called from a year crate's build script and
+/// included next to its `PUZZLES`:
+///
+/// ```text
+/// #[cfg(test)]
+/// mod sample_tests {
+///     include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+/// }
+/// ```
+pub fn emit(data_dir: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+    let data_dir = data_dir.as_ref().canonicalize()?;
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let mut tests = String::new();
+    for input in list_inputs(&data_dir)? {
+        let name = input.file_name().unwrap_or_default().to_string_lossy();
+        let Some(number) = input_day(&input) else {
+            continue;
+        };
+        if !name.contains("-sample") {
+            continue;
+        }
+        let ident = name.replace(|ch: char| !ch.is_ascii_alphanumeric(), "_");
+        let path = input.to_str().ok_or("data path is not valid UTF-8")?;
+        let Some(expected) = Expected::load(&input)? else {
+            writeln!(
+                tests,
+                "#[test]\n#[ignore = \"no answers for {}\"]\nfn {}() {{}}\n",
+                name, ident
+            )?;
+            continue;
+        };
+        for (part, gold, value) in [
+            ("silver", false, &expected.silver),
+            ("gold", true, &expected.gold),
+        ] {
+            if value.is_none() {
+                continue;
+            }
+            writeln!(
+                tests,
+                "#[test]\nfn {}_{}() -> Result<(), Box<dyn std::error::Error>> {{\n    \
+                 aoc_common_rs::sample_tests::check(crate::PUZZLES, {}, {:?}, {})\n}}\n",
+                ident, part, number, path, gold
+            )?;
+        }
+    }
+
+    fs::write(
+        Path::new(&env::var("OUT_DIR")?).join("sample_tests.rs"),
+        tests,
+    )?;
+    Ok(())
+}
+
+/// Runs a sample through its day and asserts the answer matches the sidecar.
+pub fn check(
+    puzzles: &[Puzzle],
+    number: u8,
+    input: &str,
+    gold: bool,
+) -> Result<(), Box<dyn Error>> {
+    let puzzle = puzzles
+        .iter()
+        .find(|puzzle| puzzle.number == number)
+        .ok_or_else(|| format!("day {} is not registered", number))?;
+    let input = Path::new(input);
+    let expected = Expected::load(input)?.ok_or("missing answers file")?;
+    let args = expected.args(
+        puzzle,
+        input,
+        Some(gold && matches!(puzzle.parts, Parts::Selectable)),
+    )?;
+    let answers = puzzle.solve(&fs::read_to_string(input)?, &args)?;
+
+    let expected = if gold { expected.gold } else { expected.silver };
+    let actual = answers.part(gold).map(|answer| answer.value.to_string());
+    assert_eq!(actual, expected);
+    Ok(())
+}
@@ -0,0 +1,410 @@
+//! Shortest paths over any graph described by a `SearchSpace`, keeping every
+//! optimal predecessor of the states reached so that all the optimal paths
+//! can be recovered afterwards.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry as MapEntry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+pub trait SearchSpace {
+    type State: Clone + Eq + Hash;
+
+    /// The states one move away from `state`, with the cost of each move.
+    fn neighbors(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, usize)>;
+
+    /// A lower bound of the cost from `state` to the closest goal, for `a_star`.
+    /// It must never decrease by more than the cost of a move.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+struct Node<S> {
+    cost: usize,
+    predecessors: Vec<S>,
+    settled: bool,
+}
+
+/// What a search found: the cost of the states it reached and how they were
+/// reached. Costs are final for all states no more costly than the goals,
+/// which is every reachable state when there is no goal.
+pub struct SearchResult<S> {
+    nodes: HashMap<S, Node<S>>,
+    goals: Vec<S>,
+    goal_cost: Option<usize>,
+}
+
+impl<S> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+{
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.nodes.get(state).map(|node| node.cost)
+    }
+
+    /// Every state reached with its cost.
+    pub fn costs(&self) -> impl Iterator<Item = (&S, usize)> {
+        self.nodes.iter().map(|(state, node)| (state, node.cost))
+    }
+
+    /// The goals reached at the lowest cost, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goal_cost
+    }
+
+    /// The states just before `state` on its optimal paths, none for a start.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.nodes
+            .get(state)
+            .map_or(&[], |node| node.predecessors.as_slice())
+    }
+
+    /// An optimal path from a start to `to`, both included.
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        self.nodes.get(to)?;
+        let mut path = vec![to.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All optimal paths from a start to `to`. There may be exponentially many,
+    /// see `on_paths` for just the states they go through.
+    pub fn paths(&self, to: &S) -> Vec<Vec<S>> {
+        if !self.nodes.contains_key(to) {
+            return Vec::new();
+        }
+        let mut paths = Vec::new();
+        let mut path = vec![to.clone()];
+        self.collect_paths(&mut path, &mut paths);
+        paths
+    }
+
+    fn collect_paths(&self, path: &mut Vec<S>, paths: &mut Vec<Vec<S>>) {
+        let predecessors = self.predecessors(path.last().unwrap());
+        if predecessors.is_empty() {
+            paths.push(path.iter().rev().cloned().collect());
+            return;
+        }
+        for previous in predecessors {
+            path.push(previous.clone());
+            self.collect_paths(path, paths);
+            path.pop();
+        }
+    }
+
+    /// The states on any optimal path to any of `to`.
+    pub fn on_paths<'a>(&'a self, to: impl IntoIterator<Item = &'a S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut next: Vec<&S> = to
+            .into_iter()
+            .filter(|state| self.nodes.contains_key(state))
+            .collect();
+        while let Some(state) = next.pop() {
+            if seen.insert(state.clone()) {
+                next.extend(self.predecessors(state));
+            }
+        }
+        seen
+    }
+}
+
+struct Entry<S> {
+    priority: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Entry<S> {
+    // Reversed, so that the heap pops the lowest priority first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+enum Frontier<S> {
+    Queue(VecDeque<Entry<S>>),
+    Heap(BinaryHeap<Entry<S>>),
+}
+
+impl<S> Frontier<S> {
+    fn push(&mut self, entry: Entry<S>) {
+        match self {
+            Self::Queue(queue) => queue.push_back(entry),
+            Self::Heap(heap) => heap.push(entry),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Entry<S>> {
+        match self {
+            Self::Queue(queue) => queue.pop_front(),
+            Self::Heap(heap) => heap.pop(),
+        }
+    }
+}
+
+fn search<S>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+    mut is_goal: impl FnMut(&S::State) -> bool,
+    mut frontier: Frontier<S::State>,
+    move_cost: impl Fn(usize) -> usize,
+    heuristic: impl Fn(&S::State) -> usize,
+) -> SearchResult<S::State>
+where
+    S: SearchSpace + ?Sized,
+{
+    let mut result = SearchResult {
+        nodes: HashMap::new(),
+        goals: Vec::new(),
+        goal_cost: None,
+    };
+    for start in starts {
+        if let MapEntry::Vacant(entry) = result.nodes.entry(start.clone()) {
+            entry.insert(Node {
+                cost: 0,
+                predecessors: Vec::new(),
+                settled: false,
+            });
+            frontier.push(Entry {
+                priority: heuristic(&start),
+                cost: 0,
+                state: start,
+            });
+        }
+    }
+
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = frontier.pop()
+    {
+        if result
+            .goal_cost
+            .is_some_and(|goal_cost| priority > goal_cost)
+        {
+            break;
+        }
+        let node = result.nodes.get_mut(&state).unwrap();
+        if node.settled || cost > node.cost {
+            continue;
+        }
+        node.settled = true;
+        if is_goal(&state) {
+            result.goal_cost = Some(cost);
+            result.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in space.neighbors(&state) {
+            let next_cost = cost + move_cost(step);
+            match result.nodes.entry(next) {
+                MapEntry::Occupied(mut entry) => {
+                    let node = entry.get_mut();
+                    if next_cost < node.cost {
+                        node.cost = next_cost;
+                        node.predecessors = vec![state.clone()];
+                        node.settled = false;
+                        frontier.push(Entry {
+                            priority: next_cost + heuristic(entry.key()),
+                            cost: next_cost,
+                            state: entry.key().clone(),
+                        });
+                    } else if next_cost == node.cost && !node.predecessors.contains(&state) {
+                        node.predecessors.push(state.clone());
+                    }
+                }
+                MapEntry::Vacant(entry) => {
+                    frontier.push(Entry {
+                        priority: next_cost + heuristic(entry.key()),
+                        cost: next_cost,
+                        state: entry.key().clone(),
+                    });
+                    entry.insert(Node {
+                        cost: next_cost,
+                        predecessors: vec![state.clone()],
+                        settled: false,
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Searches by number of moves, ignoring their costs, until the closest goals
+/// are reached. The costs in the result are move counts.
+pub fn bfs<S>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+    is_goal: impl FnMut(&S::State) -> bool,
+) -> SearchResult<S::State>
+where
+    S: SearchSpace + ?Sized,
+{
+    search(
+        space,
+        starts,
+        is_goal,
+        Frontier::Queue(VecDeque::new()),
+        |_| 1,
+        |_| 0,
+    )
+}
+
+/// Searches until the cheapest goals are reached. Moves must not have a zero
+/// cost for all optimal paths to be found.
+pub fn dijkstra<S>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+    is_goal: impl FnMut(&S::State) -> bool,
+) -> SearchResult<S::State>
+where
+    S: SearchSpace + ?Sized,
+{
+    search(
+        space,
+        starts,
+        is_goal,
+        Frontier::Heap(BinaryHeap::new()),
+        |step| step,
+        |_| 0,
+    )
+}
+
+/// Like `dijkstra`, but exploring first the states the heuristic of the space
+/// deems closer to a goal.
+pub fn a_star<S>(
+    space: &S,
+    starts: impl IntoIterator<Item = S::State>,
+    is_goal: impl FnMut(&S::State) -> bool,
+) -> SearchResult<S::State>
+where
+    S: SearchSpace + ?Sized,
+{
+    search(
+        space,
+        starts,
+        is_goal,
+        Frontier::Heap(BinaryHeap::new()),
+        |step| step,
+        |state| space.heuristic(state),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A graph given as `(from, to, cost)` edges.
+    struct Graph(&'static [(char, char, usize)]);
+
+    impl SearchSpace for Graph {
+        type State = char;
+
+        fn neighbors(&self, state: &char) -> impl IntoIterator<Item = (char, usize)> {
+            self.0
+                .iter()
+                .filter(move |(from, _, _)| from == state)
+                .map(|&(_, to, cost)| (to, cost))
+        }
+    }
+
+    // a -1-> b -1-> d -1-> e, a -2-> c -1-> e, a -5-> e, d -1-> f
+    const GRAPH: Graph = Graph(&[
+        ('a', 'b', 1),
+        ('a', 'c', 2),
+        ('a', 'e', 5),
+        ('b', 'd', 1),
+        ('c', 'e', 1),
+        ('d', 'e', 1),
+        ('d', 'f', 1),
+    ]);
+
+    #[test]
+    fn finds_cheapest_paths() {
+        let result = dijkstra(&GRAPH, ['a'], |&state| state == 'e');
+        assert_eq!(result.goals(), ['e']);
+        assert_eq!(result.goal_cost(), Some(3));
+        assert_eq!(result.cost(&'d'), Some(2));
+        assert_eq!(result.predecessors(&'e').len(), 2);
+        let mut paths = result.paths(&'e');
+        paths.sort();
+        assert_eq!(paths, [vec!['a', 'b', 'd', 'e'], vec!['a', 'c', 'e']]);
+        assert!(paths.contains(&result.path(&'e').unwrap()));
+        assert_eq!(
+            result.on_paths([&'e']),
+            HashSet::from(['a', 'b', 'c', 'd', 'e'])
+        );
+    }
+
+    #[test]
+    fn finds_fewest_moves() {
+        let result = bfs(&GRAPH, ['a'], |&state| state == 'e');
+        assert_eq!(result.goal_cost(), Some(1));
+        assert_eq!(result.paths(&'e'), [vec!['a', 'e']]);
+    }
+
+    #[test]
+    fn explores_everything_without_goal() {
+        let result = dijkstra(&GRAPH, ['b', 'c'], |_| false);
+        assert_eq!(result.goal_cost(), None);
+        let mut costs: Vec<_> = result.costs().map(|(&state, cost)| (state, cost)).collect();
+        costs.sort();
+        assert_eq!(costs, [('b', 0), ('c', 0), ('d', 1), ('e', 1), ('f', 2)]);
+        assert_eq!(result.cost(&'a'), None);
+        assert_eq!(result.paths(&'a'), Vec::<Vec<char>>::new());
+    }
+
+    /// An open 10x10 grid, where moving costs one, with the goal in (5, 5).
+    struct Grid;
+
+    impl SearchSpace for Grid {
+        type State = (i32, i32);
+
+        fn neighbors(&self, &(x, y): &(i32, i32)) -> impl IntoIterator<Item = ((i32, i32), usize)> {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .map(|next| (next, 1))
+        }
+
+        fn heuristic(&self, &(x, y): &(i32, i32)) -> usize {
+            (x.abs_diff(5) + y.abs_diff(5)) as usize
+        }
+    }
+
+    #[test]
+    fn a_star_agrees_with_dijkstra() {
+        let guided = a_star(&Grid, [(0, 0)], |&state| state == (5, 5));
+        let blind = dijkstra(&Grid, [(0, 0)], |&state| state == (5, 5));
+        assert_eq!(guided.goal_cost(), Some(10));
+        assert_eq!(blind.goal_cost(), Some(10));
+        assert!(guided.costs().count() < blind.costs().count());
+        assert_eq!(guided.on_paths(guided.goals()).len(), 36);
+        assert_eq!(guided.paths(&(5, 5)).len(), 252);
+    }
+}
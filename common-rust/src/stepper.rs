@@ -0,0 +1,322 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead, IsTerminal, Write},
+};
+
+use crate::terrain::Terrain;
+
+/// A simulation that can be watched one step at a time.
+pub trait Steppable {
+    type Cell: Display;
+
+    /// Performs the next step, returning false without changing anything once
+    /// the simulation is over.
+    fn step(&mut self) -> bool;
+    fn render(&self) -> Terrain<Self::Cell>;
+    fn status(&self) -> String;
+}
+
+const HELP: &str = "\
+[s] [N]    step once, or N times
+b [N]      step back once, or N times
+j N        jump to step N
+u TEXT     run until the status contains TEXT
+r          run until the end
+q          quit";
+
+/// Steps through a simulation, keeping a copy of it every `interval` steps so
+/// that going back only replays the steps since the closest one.
+pub struct Stepper<S> {
+    state: S,
+    step: usize,
+    finished: bool,
+    snapshots: Vec<S>,
+    interval: usize,
+    limit: usize,
+    clear_screen: bool,
+}
+
+impl<S> Stepper<S>
+where
+    S: Steppable + Clone,
+{
+    pub fn new(state: S) -> Self {
+        Self {
+            snapshots: vec![state.clone()],
+            state,
+            step: 0,
+            finished: false,
+            interval: 64,
+            limit: 1_000_000,
+            clear_screen: false,
+        }
+    }
+
+    pub fn with_interval(mut self, interval: usize) -> Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    /// Sets how many steps running at once may take at most, for simulations
+    /// which never end.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn with_clear_screen(mut self, clear_screen: bool) -> Self {
+        self.clear_screen = clear_screen;
+        self
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn step_count(&self) -> usize {
+        self.step
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn step(&mut self) -> bool {
+        if self.finished || !self.state.step() {
+            self.finished = true;
+            return false;
+        }
+        self.step += 1;
+        if self.step == self.snapshots.len() * self.interval {
+            self.snapshots.push(self.state.clone());
+        }
+        true
+    }
+
+    /// Moves to the given step, returning false if the simulation ends
+    /// before it.
+    pub fn jump_to(&mut self, target: usize) -> bool {
+        if target < self.step {
+            let snapshot = (target / self.interval).min(self.snapshots.len() - 1);
+            self.state = self.snapshots[snapshot].clone();
+            self.step = snapshot * self.interval;
+            self.finished = false;
+        }
+        while self.step < target {
+            if !self.step() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Goes back the given number of steps, returning false if it had to
+    /// stop at the start.
+    pub fn step_back(&mut self, steps: usize) -> bool {
+        let enough = steps <= self.step;
+        self.jump_to(self.step.saturating_sub(steps));
+        enough
+    }
+
+    /// Steps until `stop` holds, the simulation ends or the limit is reached,
+    /// returning whether `stop` held.
+    pub fn run_until(&mut self, mut stop: impl FnMut(&S) -> bool) -> bool {
+        for _ in 0..self.limit {
+            if !self.step() {
+                return false;
+            }
+            if stop(&self.state) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn draw(&self, out: &mut impl Write, message: &str) -> io::Result<()> {
+        if self.clear_screen {
+            out.write_all(b"\x1b[H\x1b[2J")?;
+        }
+        writeln!(out, "{}", self.state.render())?;
+        writeln!(
+            out,
+            "step {}{}: {}",
+            self.step,
+            if self.finished { " (finished)" } else { "" },
+            self.state.status()
+        )?;
+        if !message.is_empty() {
+            writeln!(out, "{}", message)?;
+        }
+        write!(out, "> ")?;
+        out.flush()
+    }
+
+    /// Reads commands from `input` until it ends or `q` is given, drawing the
+    /// simulation to `out` after each one.
+    pub fn interact(&mut self, input: impl BufRead, mut out: impl Write) -> io::Result<()> {
+        self.draw(&mut out, HELP)?;
+        for line in input.lines() {
+            let line = line?;
+            let (command, arg) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+            let (command, arg) = match command.parse::<usize>() {
+                Ok(_) if arg.is_empty() => ("s", command),
+                _ => (command, arg.trim()),
+            };
+            let count = if arg.is_empty() { Ok(1) } else { arg.parse() };
+            let (success, failure) = match (command, count) {
+                ("" | "s", Ok(count)) => (
+                    (0..count).all(|_| self.step()),
+                    "the simulation is over".to_owned(),
+                ),
+                ("b", Ok(count)) => (self.step_back(count), "went back to the start".to_owned()),
+                ("j", Ok(target)) if !arg.is_empty() => (
+                    self.jump_to(target),
+                    "the simulation ended first".to_owned(),
+                ),
+                ("u", _) if !arg.is_empty() => (
+                    self.run_until(|state| state.status().contains(arg)),
+                    format!("{:?} never showed up", arg),
+                ),
+                ("r", _) => {
+                    self.run_until(|_| false);
+                    (self.finished, format!("stopped after {} steps", self.limit))
+                }
+                ("q", _) => break,
+                ("h" | "?", _) => (false, HELP.to_owned()),
+                _ => (
+                    false,
+                    format!("unknown command {:?}, h for help", line.trim()),
+                ),
+            };
+            self.draw(&mut out, if success { "" } else { &failure })?;
+        }
+        writeln!(out)
+    }
+}
+
+/// Fails unless stdin is a terminal to read the stepping commands from.
+pub fn check_terminal() -> Result<(), Box<dyn Error>> {
+    if io::stdin().is_terminal() {
+        Ok(())
+    } else {
+        Err("--step needs an interactive terminal on stdin".into())
+    }
+}
+
+/// Lets the user step through `state` on the terminal.
+pub fn interact<S>(state: S) -> Result<(), Box<dyn Error>>
+where
+    S: Steppable + Clone,
+{
+    check_terminal()?;
+    Stepper::new(state)
+        .with_clear_screen(io::stdout().is_terminal())
+        .interact(io::stdin().lock(), io::stdout().lock())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct Walker {
+        position: usize,
+        end: usize,
+    }
+
+    impl Steppable for Walker {
+        type Cell = char;
+
+        fn step(&mut self) -> bool {
+            if self.position == self.end {
+                return false;
+            }
+            self.position += 1;
+            true
+        }
+
+        fn render(&self) -> Terrain<char> {
+            Terrain::new_with(self.end + 1, 1, |pt| {
+                if pt.0 == self.position {
+                    '@'
+                } else {
+                    '.'
+                }
+            })
+        }
+
+        fn status(&self) -> String {
+            format!("at {}", self.position)
+        }
+    }
+
+    fn walker(end: usize) -> Stepper<Walker> {
+        Stepper::new(Walker { position: 0, end }).with_interval(2)
+    }
+
+    fn run(stepper: &mut Stepper<Walker>, script: &str) -> String {
+        let mut out = Vec::new();
+        stepper.interact(script.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn steps_with_a_count() {
+        let mut stepper = walker(5);
+        let out = run(&mut stepper, "s 3\n\nq\nignored\n");
+        assert_eq!(stepper.step_count(), 4);
+        assert_eq!(stepper.state().position, 4);
+        assert!(out.starts_with(&format!("@.....\nstep 0: at 0\n{}\n> ", HELP)));
+        assert!(out.ends_with("...@..\nstep 3: at 3\n> ....@.\nstep 4: at 4\n> \n"));
+    }
+
+    #[test]
+    fn goes_back_past_the_start() {
+        let mut stepper = walker(5);
+        let out = run(&mut stepper, "3\nb 2\nb 5\n");
+        assert_eq!(stepper.step_count(), 0);
+        assert!(out.contains(".@....\nstep 1: at 1\n> "));
+        assert!(out.ends_with("@.....\nstep 0: at 0\nwent back to the start\n> \n"));
+    }
+
+    #[test]
+    fn jumps_beyond_the_end() {
+        let mut stepper = walker(5);
+        let out = run(&mut stepper, "j 9\n");
+        assert!(stepper.is_finished());
+        assert_eq!(stepper.step_count(), 5);
+        assert!(out.ends_with(".....@\nstep 5 (finished): at 5\nthe simulation ended first\n> \n"));
+
+        let out = run(&mut stepper, "j 3\n");
+        assert!(!stepper.is_finished());
+        assert!(out.ends_with("...@..\nstep 3: at 3\n> \n"));
+    }
+
+    #[test]
+    fn runs_until_the_status_matches() {
+        let mut stepper = walker(5);
+        let out = run(&mut stepper, "u at 4\n");
+        assert_eq!(stepper.step_count(), 4);
+        assert!(out.ends_with("step 4: at 4\n> \n"));
+
+        let out = run(&mut stepper, "u at 9\n");
+        assert!(stepper.is_finished());
+        assert!(out.ends_with("step 5 (finished): at 5\n\"at 9\" never showed up\n> \n"));
+    }
+
+    #[test]
+    fn runs_to_the_end_or_the_limit() {
+        let mut stepper = walker(5);
+        let out = run(&mut stepper, "r\n");
+        assert_eq!(stepper.step_count(), 5);
+        assert!(out.ends_with("step 5 (finished): at 5\n> \n"));
+
+        let mut stepper = walker(5).with_limit(3);
+        let out = run(&mut stepper, "r\n");
+        assert!(!stepper.is_finished());
+        assert_eq!(stepper.state().position, 3);
+        assert!(out.ends_with("step 3: at 3\nstopped after 3 steps\n> \n"));
+    }
+}
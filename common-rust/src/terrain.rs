@@ -1,27 +1,37 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
     fmt::{Display, Formatter, Result as FmtResult, Write},
-    mem::replace,
-    ops::{Deref, DerefMut, Index, IndexMut},
+    mem::take,
+    ops::{Index, IndexMut},
 };
 
 use itertools::Itertools;
 
-use crate::point::{Direction2, Point2};
+use crate::{
+    answers::Answers,
+    error::AocError,
+    line_stream::LineStreamHandler,
+    point::{Direction2, Direction8, Point2},
+};
 
+/// A grid of cells stored row by row in a single `Vec`.
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct Terrain<T> {
     width: usize,
     height: usize,
-    terrain: Vec<Vec<T>>,
+    cells: Vec<T>,
 }
 
 impl<T> Terrain<T> {
+    pub fn builder() -> TerrainBuilder<T> {
+        TerrainBuilder::new()
+    }
     pub fn new() -> Self {
         Self {
             width: 0,
             height: 0,
-            terrain: Vec::new(),
+            cells: Vec::new(),
         }
     }
     pub fn new_with(
@@ -29,19 +39,17 @@ impl<T> Terrain<T> {
         height: usize,
         mut generator: impl FnMut(Point2<usize>) -> T,
     ) -> Self {
-        let mut terrain = Self {
-            width,
-            height,
-            terrain: Vec::with_capacity(height),
-        };
+        let mut cells = Vec::with_capacity(width * height);
         for y in 0usize..height {
-            let mut row = terrain.new_row();
             for x in 0usize..width {
-                row.push(generator(Point2(x, y)));
+                cells.push(generator(Point2(x, y)));
             }
-            terrain.terrain.push(row);
         }
-        terrain
+        Self {
+            width,
+            height,
+            cells,
+        }
     }
     pub fn width(&self) -> usize {
         self.width
@@ -55,12 +63,68 @@ impl<T> Terrain<T> {
     pub fn new_row(&self) -> Vec<T> {
         Vec::with_capacity(self.width)
     }
-    pub fn push_row(&mut self, row: Vec<T>) {
-        if row.len() > self.width {
-            self.width = row.len();
+    /// Appends a row, which must be as wide as the ones before it. The first
+    /// row sets the width.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) -> Result<(), Box<dyn Error>> {
+        let start = self.cells.len();
+        self.cells.extend(row);
+        let len = self.cells.len() - start;
+        if self.height == 0 {
+            self.width = len;
+        } else if len != self.width {
+            self.cells.truncate(start);
+            return Err(AocError::invalid_input(format!(
+                "row {} is {} cells wide instead of {}",
+                self.height + 1,
+                len,
+                self.width
+            ))
+            .into());
         }
-        self.terrain.push(row);
         self.height += 1;
+        Ok(())
+    }
+    pub fn get(&self, pt: Point2<usize>) -> Option<&T> {
+        if pt.0 < self.width && pt.1 < self.height {
+            Some(&self.cells[pt.1 * self.width + pt.0])
+        } else {
+            None
+        }
+    }
+    pub fn get_mut(&mut self, pt: Point2<usize>) -> Option<&mut T> {
+        if pt.0 < self.width && pt.1 < self.height {
+            Some(&mut self.cells[pt.1 * self.width + pt.0])
+        } else {
+            None
+        }
+    }
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+    /// The cells of a column, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} out of {}", x, self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+    pub fn column_mut(&mut self, x: usize) -> impl DoubleEndedIterator<Item = &mut T> {
+        assert!(x < self.width, "column {} out of {}", x, self.width);
+        self.cells[x..].iter_mut().step_by(self.width)
+    }
+    /// All cells in the same order as `points`.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter()
+    }
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.cells.iter_mut()
     }
     pub fn points(&self) -> impl Iterator<Item = Point2<usize>> {
         (0..self.height)
@@ -104,6 +168,22 @@ impl<T> Terrain<T> {
             }
         })
     }
+    pub fn neighbor8(&self, pt: Point2<usize>, towards: Direction8) -> Option<Point2<usize>> {
+        pt.try_next_towards8(towards)
+            .filter(|next| next.0 < self.width && next.1 < self.height)
+    }
+    /// The neighbors of a point including the diagonal ones.
+    pub fn neighbors8(
+        &self,
+        pt: Point2<usize>,
+    ) -> impl Iterator<Item = (Direction8, Point2<usize>)> {
+        let size = self.size();
+        Direction8::all().filter_map(move |dir| {
+            pt.try_next_towards8(dir)
+                .filter(|next| next.0 < size.0 && next.1 < size.1)
+                .map(|next| (dir, next))
+        })
+    }
     pub fn walk_until(
         &self,
         from: Point2<usize>,
@@ -207,6 +287,59 @@ impl<T> Terrain<T> {
             }
         }
     }
+    /// The number of steps to each point from the closest of `sources`, moving
+    /// between neighbors `from` and `to` where `passable(from, to)` holds.
+    pub fn distances_from(
+        &self,
+        sources: impl IntoIterator<Item = Point2<usize>>,
+        passable: impl FnMut(&T, &T) -> bool,
+    ) -> Terrain<Option<usize>> {
+        self.distances(sources, passable)
+    }
+    /// The number of steps from each point to the closest of `targets`, moving
+    /// like `distances_from`.
+    pub fn distances_to(
+        &self,
+        targets: impl IntoIterator<Item = Point2<usize>>,
+        mut passable: impl FnMut(&T, &T) -> bool,
+    ) -> Terrain<Option<usize>> {
+        self.distances(targets, |from, to| passable(to, from))
+    }
+    fn distances(
+        &self,
+        sources: impl IntoIterator<Item = Point2<usize>>,
+        mut passable: impl FnMut(&T, &T) -> bool,
+    ) -> Terrain<Option<usize>> {
+        let mut distances = Terrain::new_with(self.width, self.height, |_| None);
+        let mut visited = Visited::new(self.cells.len());
+        let mut queue = VecDeque::new();
+        for pt in sources {
+            if visited.insert(self.cell_index(pt)) {
+                distances[pt] = Some(0);
+                queue.push_back((pt, 0));
+            }
+        }
+        while let Some((pt, distance)) = queue.pop_front() {
+            for (_, neighbor) in self.neighbors(pt) {
+                let index = self.cell_index(neighbor);
+                if !visited.contains(index) && passable(&self[pt], &self.cells[index]) {
+                    visited.insert(index);
+                    distances[neighbor] = Some(distance + 1);
+                    queue.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+    fn cell_index(&self, pt: Point2<usize>) -> usize {
+        assert!(
+            pt.0 < self.width && pt.1 < self.height,
+            "{} out of {}",
+            pt,
+            self.size()
+        );
+        pt.1 * self.width + pt.0
+    }
     pub fn flood_fill_mut(
         &mut self,
         from: Point2<usize>,
@@ -218,7 +351,7 @@ impl<T> Terrain<T> {
         let mut next = HashSet::new();
         next.insert(from);
         while !next.is_empty() {
-            for pt in replace(&mut next, HashSet::new()) {
+            for pt in take(&mut next) {
                 visited.insert(pt);
                 action(self, level, pt);
                 for (direction, neighbor) in self.neighbors(pt) {
@@ -226,7 +359,7 @@ impl<T> Terrain<T> {
                         continue;
                     }
 
-                    if predicate(&self, level, pt, direction, neighbor) {
+                    if predicate(self, level, pt, direction, neighbor) {
                         next.insert(neighbor);
                     }
                 }
@@ -245,7 +378,7 @@ impl<T> Terrain<T> {
         let mut next = HashSet::new();
         next.insert(from);
         while !next.is_empty() {
-            for pt in replace(&mut next, HashSet::new()) {
+            for pt in take(&mut next) {
                 visited.insert(pt);
                 action(self, level, pt);
                 for (direction, neighbor) in self.neighbors(pt) {
@@ -263,27 +396,25 @@ impl<T> Terrain<T> {
     }
 }
 
-impl<T> Default for Terrain<T> {
-    fn default() -> Self {
-        Self {
-            width: Default::default(),
-            height: Default::default(),
-            terrain: Default::default(),
+impl<T> Terrain<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<Box<dyn Error>>,
+{
+    /// Parses a terrain with one character per cell, along with the positions
+    /// of the given marker characters.
+    pub fn parse(text: &str, markers: &str) -> Result<(Self, Markers), Box<dyn Error>> {
+        let mut builder = Self::builder().with_markers(markers);
+        for line in text.lines() {
+            builder.push_line(line)?;
         }
+        Ok(builder.build())
     }
 }
 
-impl<T> Deref for Terrain<T> {
-    type Target = Vec<Vec<T>>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.terrain
-    }
-}
-
-impl<T> DerefMut for Terrain<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.terrain
+impl<T> Default for Terrain<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -291,13 +422,25 @@ impl<T> Index<Point2<usize>> for Terrain<T> {
     type Output = T;
 
     fn index(&self, index: Point2<usize>) -> &Self::Output {
-        &self.terrain[index.1][index.0]
+        assert!(
+            index.0 < self.width,
+            "column {} out of {}",
+            index.0,
+            self.width
+        );
+        &self.cells[index.1 * self.width + index.0]
     }
 }
 
 impl<T> IndexMut<Point2<usize>> for Terrain<T> {
     fn index_mut(&mut self, index: Point2<usize>) -> &mut Self::Output {
-        &mut self.terrain[index.1][index.0]
+        assert!(
+            index.0 < self.width,
+            "column {} out of {}",
+            index.0,
+            self.width
+        );
+        &mut self.cells[index.1 * self.width + index.0]
     }
 }
 
@@ -307,7 +450,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         let mut first = true;
-        for row in &self.terrain {
+        for row in self.rows() {
             if first {
                 first = false;
             } else {
@@ -320,3 +463,315 @@ where
         Ok(())
     }
 }
+
+/// One bit per cell of a terrain.
+struct Visited(Vec<u64>);
+
+impl Visited {
+    fn new(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        self.0[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns whether the cell wasn't visited yet.
+    fn insert(&mut self, index: usize) -> bool {
+        let newly = !self.contains(index);
+        self.0[index / 64] |= 1 << (index % 64);
+        newly
+    }
+}
+
+/// A character of a terrain which isn't a valid cell, at its 1-based row and
+/// column.
+#[derive(Debug)]
+pub struct CellError {
+    pub row: usize,
+    pub column: usize,
+    pub ch: char,
+    pub source: Box<dyn Error>,
+}
+
+impl Display for CellError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{:?} at row {}, column {}: {}",
+            self.ch, self.row, self.column, self.source
+        )
+    }
+}
+
+impl Error for CellError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Where the marker characters of a parsed terrain were found, e.g. the `S`
+/// and `E` of a maze.
+#[derive(Clone, Default, Debug)]
+pub struct Markers(HashMap<char, Vec<Point2<usize>>>);
+
+impl Markers {
+    /// All positions of a marker, from top to bottom and left to right.
+    pub fn all(&self, marker: char) -> &[Point2<usize>] {
+        self.0.get(&marker).map_or(&[], Vec::as_slice)
+    }
+
+    /// The position of a marker which must appear exactly once.
+    pub fn one(&self, marker: char) -> Result<Point2<usize>, Box<dyn Error>> {
+        match self.all(marker) {
+            [pt] => Ok(*pt),
+            [] => Err(AocError::invalid_input(format!("no {:?} in the terrain", marker)).into()),
+            pts => Err(AocError::invalid_input(format!(
+                "{} {:?} in the terrain instead of one",
+                pts.len(),
+                marker
+            ))
+            .into()),
+        }
+    }
+}
+
+/// Builds a terrain one line of text at a time, converting each character to
+/// a cell and remembering where the marker characters are. Markers are
+/// converted like any other character.
+pub struct TerrainBuilder<T> {
+    terrain: Terrain<T>,
+    markers: Vec<char>,
+    found: Markers,
+}
+
+impl<T> TerrainBuilder<T> {
+    pub fn new() -> Self {
+        Self {
+            terrain: Terrain::new(),
+            markers: Vec::new(),
+            found: Markers::default(),
+        }
+    }
+
+    pub fn with_markers(mut self, markers: &str) -> Self {
+        self.markers.extend(markers.chars());
+        self
+    }
+
+    /// The number of lines pushed so far.
+    pub fn height(&self) -> usize {
+        self.terrain.height()
+    }
+
+    pub fn build(self) -> (Terrain<T>, Markers) {
+        (self.terrain, self.found)
+    }
+}
+
+impl<T> TerrainBuilder<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<Box<dyn Error>>,
+{
+    pub fn push_line(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
+        let y = self.terrain.height();
+        let mut row = self.terrain.new_row();
+        for (x, ch) in line.chars().enumerate() {
+            let cell = T::try_from(ch).map_err(|err| CellError {
+                row: y + 1,
+                column: x + 1,
+                ch,
+                source: err.into(),
+            })?;
+            if self.markers.contains(&ch) {
+                self.found.0.entry(ch).or_default().push(Point2(x, y));
+            }
+            row.push(cell);
+        }
+        self.terrain.push_row(row)
+    }
+
+    /// Handles an input made of nothing but the terrain, passing it to
+    /// `finish` once complete.
+    ///
+    /// ```text
+    /// Terrain::builder()
+    ///     .with_markers("SE")
+    ///     .into_handler(|terrain, markers| Day16::new(terrain, &markers)?.solve())
+    /// ```
+    pub fn into_handler(
+        self,
+        finish: impl FnOnce(Terrain<T>, Markers) -> Result<Answers, Box<dyn Error>> + 'static,
+    ) -> impl LineStreamHandler
+    where
+        T: 'static,
+    {
+        TerrainHandler {
+            builder: self,
+            finish: Box::new(finish),
+        }
+    }
+}
+
+impl<T> Default for TerrainBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+type TerrainFinish<T> = Box<dyn FnOnce(Terrain<T>, Markers) -> Result<Answers, Box<dyn Error>>>;
+
+struct TerrainHandler<T> {
+    builder: TerrainBuilder<T>,
+    finish: TerrainFinish<T>,
+}
+
+impl<T> LineStreamHandler for TerrainHandler<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<Box<dyn Error>>,
+{
+    fn update(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
+        self.builder.push_line(line)
+    }
+
+    fn finish(self: Box<Self>) -> Result<Answers, Box<dyn Error>> {
+        let (terrain, markers) = self.builder.build();
+        (self.finish)(terrain, markers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Terrain<u8> {
+        Terrain::new_with(3, 2, |pt| (pt.1 * 3 + pt.0) as u8)
+    }
+
+    #[test]
+    fn accesses_rows_and_columns() {
+        let mut terrain = sample();
+        assert_eq!(terrain.row(1), &[3, 4, 5]);
+        assert_eq!(terrain.rows().collect::<Vec<_>>(), [&[0, 1, 2], &[3, 4, 5]]);
+        assert_eq!(terrain.column(2).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(terrain.get(Point2(2, 1)), Some(&5));
+        assert_eq!(terrain.get(Point2(3, 0)), None);
+        assert_eq!(terrain.get(Point2(0, 2)), None);
+
+        terrain.column_mut(0).for_each(|cell| *cell = 9);
+        terrain.row_mut(0)[2] = 7;
+        assert_eq!(terrain.to_string(), "917\n945");
+    }
+
+    #[test]
+    fn pushes_rows_of_the_same_width() {
+        let mut terrain = Terrain::new();
+        terrain.push_row("#.#".chars()).unwrap();
+        terrain.push_row("..#".chars()).unwrap();
+        assert!(terrain.push_row("#.".chars()).is_err());
+        assert!(terrain.push_row("#..#".chars()).is_err());
+        assert_eq!(terrain.size(), Point2(3, 2));
+        assert_eq!(terrain.to_string(), "#.#\n..#");
+    }
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Wall(bool);
+
+    impl TryFrom<char> for Wall {
+        type Error = &'static str;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '#' => Ok(Self(true)),
+                '.' | 'S' | 'E' => Ok(Self(false)),
+                _ => Err("not a wall or floor"),
+            }
+        }
+    }
+
+    #[test]
+    fn parses_with_markers() {
+        let (terrain, markers) = Terrain::<Wall>::parse("#S.\n..#\n.E#\n", "SE#").unwrap();
+        assert_eq!(terrain.size(), Point2(3, 3));
+        assert_eq!(terrain[Point2(2, 1)], Wall(true));
+        assert_eq!(terrain[Point2(1, 0)], Wall(false));
+        assert_eq!(markers.one('S').unwrap(), Point2(1, 0));
+        assert_eq!(markers.one('E').unwrap(), Point2(1, 2));
+        assert_eq!(markers.all('#'), [Point2(0, 0), Point2(2, 1), Point2(2, 2)]);
+        assert!(markers.one('#').is_err());
+        assert!(markers.one('X').is_err());
+        assert_eq!(markers.all('X'), []);
+    }
+
+    #[test]
+    fn reports_unknown_cells() {
+        let err = Terrain::<Wall>::parse("#.#\n.x.", "").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'x' at row 2, column 2: not a wall or floor"
+        );
+    }
+
+    #[test]
+    fn finds_neighbors8() {
+        let terrain = sample();
+        let neighbors = |pt| terrain.neighbors8(pt).collect::<Vec<_>>();
+        assert_eq!(
+            neighbors(Point2(0, 0)),
+            [
+                (Direction8::Right, Point2(1, 0)),
+                (Direction8::DownRight, Point2(1, 1)),
+                (Direction8::Down, Point2(0, 1)),
+            ]
+        );
+        assert_eq!(neighbors(Point2(1, 1)).len(), 5);
+        assert_eq!(
+            terrain.neighbor8(Point2(2, 1), Direction8::UpLeft),
+            Some(Point2(1, 0))
+        );
+        assert_eq!(terrain.neighbor8(Point2(2, 1), Direction8::UpRight), None);
+    }
+
+    #[test]
+    fn measures_distances() {
+        let (terrain, _) = Terrain::<Wall>::parse("..#.\n.#..\n....", "").unwrap();
+        let open = |_: &Wall, to: &Wall| !to.0;
+        let show = |distances: Terrain<Option<usize>>| {
+            distances
+                .rows()
+                .map(|row| {
+                    row.iter()
+                        .map(|distance| distance.map_or('#', |d| (b'0' + d as u8) as char))
+                        .collect::<String>()
+                })
+                .join("\n")
+        };
+        assert_eq!(
+            show(terrain.distances_from([Point2(0, 0)], open)),
+            "01#7\n1#56\n2345"
+        );
+        assert_eq!(
+            show(terrain.distances_from([Point2(0, 0), Point2(3, 0)], open)),
+            "01#0\n1#21\n2332"
+        );
+
+        // Climbing at most one step up, but jumping down any height.
+        let heights = [0u8, 1, 3, 2];
+        let terrain = Terrain::new_with(4, 1, |pt| heights[pt.0]);
+        let climbable = |from: &u8, to: &u8| *to <= from + 1;
+        assert_eq!(
+            show(terrain.distances_from([Point2(0, 0)], climbable)),
+            "01##"
+        );
+        assert_eq!(
+            show(terrain.distances_to([Point2(0, 0)], climbable)),
+            "0123"
+        );
+        assert_eq!(
+            show(terrain.distances_to([Point2(2, 0)], climbable)),
+            "##01"
+        );
+    }
+}
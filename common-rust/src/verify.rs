@@ -0,0 +1,344 @@
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    answers::Answers,
+    day::{solve_guarded, Args, ParamKind, Parts, Puzzle},
+    input::{is_sample, Input},
+};
+
+/// Expected answers for an input, read from a `.answers` file next to it.
+///
+/// ```text
+/// silver: 24000
+/// gold: 45000
+/// ```
+///
+/// Any other key sets a parameter of the puzzle (e.g. `steps: 6`), flags take
+/// `true` or a count. A key with an empty value starts a multi-line value made
+/// of the following lines indented by two spaces.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Expected {
+    pub silver: Option<String>,
+    pub gold: Option<String>,
+    pub params: Vec<(String, String)>,
+}
+
+impl Expected {
+    pub fn load(input: &Path) -> Result<Option<Self>, Box<dyn Error>> {
+        match fs::read_to_string(sidecar_path(input)) {
+            Ok(contents) => Ok(Some(contents.parse()?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Builds the arguments to run `input` with, selecting a single part when
+    /// `gold` is given.
+    pub fn args(
+        &self,
+        puzzle: &Puzzle,
+        input: &Path,
+        gold: Option<bool>,
+    ) -> Result<Args, Box<dyn Error>> {
+        let mut args = Args::new();
+        if let Some(gold) = gold {
+            args.set_count(if gold { "gold" } else { "silver" }, 1);
+        }
+        if is_sample(input) {
+            args.set_count("sample", 1);
+        }
+        for (name, value) in &self.params {
+            let param = puzzle
+                .all_params()
+                .find(|param| param.name == name)
+                .ok_or_else(|| format!("unknown parameter {}", name))?;
+            match param.kind {
+                ParamKind::Flag | ParamKind::Count => args.set_count(
+                    param.name,
+                    match value.as_str() {
+                        "true" => 1,
+                        "false" => 0,
+                        count => count.parse()?,
+                    },
+                ),
+                ParamKind::Value { .. } => args.set_value(param.name, value.clone()),
+            }
+        }
+        Ok(args)
+    }
+}
+
+impl FromStr for Expected {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries: Vec<(String, String)> = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            if let Some(continuation) = line.strip_prefix("  ") {
+                match entries.last_mut() {
+                    Some((_, value)) => {
+                        if !value.is_empty() {
+                            value.push('\n');
+                        }
+                        value.push_str(continuation);
+                    }
+                    None => return Err(format!("line {}: unexpected indentation", i + 1).into()),
+                }
+                continue;
+            }
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("line {}: expected `key: value`", i + 1))?;
+            entries.push((key.trim().to_owned(), value.trim().to_owned()));
+        }
+
+        let mut expected = Self::default();
+        for (key, value) in entries {
+            match key.as_str() {
+                "silver" => expected.silver = Some(value),
+                "gold" => expected.gold = Some(value),
+                _ => expected.params.push((key, value)),
+            }
+        }
+        Ok(expected)
+    }
+}
+
+/// Formats a `key: value` line of a `.answers` file, a multi-line value going
+/// on the following lines, indented.
+pub fn answers_entry(key: &str, value: &str) -> String {
+    if !value.contains('\n') {
+        return format!("{}: {}\n", key, value);
+    }
+    let mut entry = format!("{}:\n", key);
+    for line in value.lines() {
+        entry.push_str("  ");
+        entry.push_str(line);
+        entry.push('\n');
+    }
+    entry
+}
+
+pub fn sidecar_path(input: &Path) -> PathBuf {
+    let mut name = input.file_name().unwrap_or_default().to_owned();
+    name.push(".answers");
+    input.with_file_name(name)
+}
+
+pub fn data_dir(root: &Path, year: u16) -> PathBuf {
+    root.join(format!("{}-data", year))
+}
+
+/// Lists the `dayNN-*` files without extension in a data directory, sorted by
+/// name.
+pub fn list_inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_file() && input_day(&path).is_some() {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+pub fn input_day(input: &Path) -> Option<u8> {
+    let name = input.file_name()?.to_str()?;
+    let (day, _) = name.strip_prefix("day")?.split_once('-')?;
+    if name.contains('.') || day.len() != 2 {
+        return None;
+    }
+    day.parse().ok()
+}
+
+pub fn inputs(root: &Path, puzzle: &Puzzle) -> io::Result<Vec<PathBuf>> {
+    Ok(list_inputs(&data_dir(root, puzzle.year))?
+        .into_iter()
+        .filter(|input| input_day(input) == Some(puzzle.number))
+        .collect())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+impl Status {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Missing => "missing",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub year: u16,
+    pub number: u8,
+    pub input: PathBuf,
+    pub part: Option<&'static str>,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub status: Status,
+}
+
+fn solve(puzzle: &Puzzle, args: &Args, input: &Path) -> Result<Answers, String> {
+    solve_guarded(puzzle, args, &Input::File(input.to_owned())).map_err(|err| err.to_string())
+}
+
+/// Runs every input of a puzzle and compares the answers with its sidecar.
+pub fn verify(root: &Path, puzzle: &Puzzle) -> Result<Vec<Outcome>, Box<dyn Error>> {
+    let mut outcomes = Vec::new();
+    for input in inputs(root, puzzle)? {
+        let outcome = |part, expected, actual, status| Outcome {
+            year: puzzle.year,
+            number: puzzle.number,
+            input: input.clone(),
+            part,
+            expected,
+            actual,
+            status,
+        };
+        let expected = match Expected::load(&input) {
+            Ok(Some(expected)) if expected.silver.is_some() || expected.gold.is_some() => expected,
+            Ok(_) => {
+                outcomes.push(outcome(None, None, None, Status::Missing));
+                continue;
+            }
+            Err(err) => {
+                outcomes.push(outcome(None, None, Some(err.to_string()), Status::Error));
+                continue;
+            }
+        };
+
+        let parts = [
+            ("silver", false, &expected.silver),
+            ("gold", true, &expected.gold),
+        ];
+        let mut shared = None;
+        for (part, gold, value) in parts {
+            let Some(value) = value else {
+                continue;
+            };
+            let result = if matches!(puzzle.parts, Parts::Selectable) {
+                expected
+                    .args(puzzle, &input, Some(gold))
+                    .map_err(|err| err.to_string())
+                    .and_then(|args| solve(puzzle, &args, &input))
+            } else {
+                shared
+                    .get_or_insert_with(|| {
+                        expected
+                            .args(puzzle, &input, Some(false))
+                            .map_err(|err| err.to_string())
+                            .and_then(|args| solve(puzzle, &args, &input))
+                    })
+                    .clone()
+            };
+            outcomes.push(match result {
+                Ok(answers) => {
+                    let actual = answers.part(gold).map(|answer| answer.value.to_string());
+                    let status = if actual.as_ref() == Some(value) {
+                        Status::Pass
+                    } else {
+                        Status::Fail
+                    };
+                    outcome(Some(part), Some(value.clone()), actual, status)
+                }
+                Err(err) => outcome(Some(part), Some(value.clone()), Some(err), Status::Error),
+            });
+        }
+    }
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day::Param;
+
+    const PUZZLE: Puzzle = Puzzle::new(
+        2024,
+        20,
+        "Race Condition",
+        Parts::Selectable,
+        |_| unreachable!(),
+    )
+    .with_params(&[Param::value("threshold", 'm'), Param::flag("verbose", 'v')]);
+
+    #[test]
+    fn parses_answers_and_params() {
+        let expected: Expected = "# from the puzzle text\nsilver: 44\n\nthreshold: 50\ngold: 285\n"
+            .parse()
+            .unwrap();
+        assert_eq!(expected.silver.as_deref(), Some("44"));
+        assert_eq!(expected.gold.as_deref(), Some("285"));
+        assert_eq!(expected.params, [("threshold".to_owned(), "50".to_owned())]);
+    }
+
+    #[test]
+    fn parses_multi_line_values() {
+        let expected: Expected = "silver: 13140\ngold:\n  ##..\n  #..#\n# done\n"
+            .parse()
+            .unwrap();
+        assert_eq!(expected.silver.as_deref(), Some("13140"));
+        assert_eq!(expected.gold.as_deref(), Some("##..\n#..#"));
+
+        assert!("  ##..\nsilver: 1".parse::<Expected>().is_err());
+        assert!("silver 1".parse::<Expected>().is_err());
+    }
+
+    #[test]
+    fn writes_entries_that_parse_back() {
+        assert_eq!(answers_entry("silver", "13140"), "silver: 13140\n");
+        let screen = "##..\n\n#..#";
+        let entry = answers_entry("gold", screen);
+        assert_eq!(entry, "gold:\n  ##..\n  \n  #..#\n");
+        let expected: Expected = (answers_entry("silver", "13140") + &entry).parse().unwrap();
+        assert_eq!(expected.silver.as_deref(), Some("13140"));
+        assert_eq!(expected.gold.as_deref(), Some(screen));
+    }
+
+    #[test]
+    fn allows_a_missing_part() {
+        let expected: Expected = "gold: 2".parse().unwrap();
+        assert_eq!(expected.silver, None);
+        assert_eq!(expected.gold.as_deref(), Some("2"));
+        assert_eq!("".parse::<Expected>().unwrap(), Expected::default());
+    }
+
+    #[test]
+    fn builds_args_from_params() {
+        let input = Path::new("2024-data/day20-sample");
+        let expected: Expected = "threshold: 50\nverbose: true".parse().unwrap();
+        let args = expected.args(&PUZZLE, input, Some(true)).unwrap();
+        assert!(args.flag("gold"));
+        assert!(args.flag("sample"));
+        assert!(args.flag("verbose"));
+        assert_eq!(args.value::<usize>("threshold").unwrap(), Some(50));
+
+        let unknown: Expected = "steps: 6".parse().unwrap();
+        assert!(unknown.args(&PUZZLE, input, None).is_err());
+    }
+}
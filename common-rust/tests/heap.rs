@@ -0,0 +1,45 @@
+//! Kept out of the unit tests: counting needs the allocator installed for the
+//! whole test binary, and other tests allocating in parallel would skew it.
+
+use std::hint::black_box;
+
+use aoc_common_rs::heap::{self, CountingAllocator, HeapStats};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn measures_from_what_is_live() {
+    let (len, stats) = heap::measure(|| black_box(vec![0u32; 1000]).len());
+    assert_eq!(len, 1000);
+    assert_eq!(
+        stats,
+        HeapStats {
+            peak: 4000,
+            allocated: 4000,
+            allocations: 1,
+        }
+    );
+
+    // Freeing what was allocated before neither counts nor lowers the next
+    // peak.
+    let kept = black_box(vec![0u8; 10_000]);
+    let ((), stats) = heap::measure(|| drop(kept));
+    assert_eq!(stats, HeapStats::default());
+
+    let (kept, stats) = heap::measure(|| {
+        let mut kept = black_box(Vec::<u8>::with_capacity(500));
+        drop(black_box(vec![0u8; 2000]));
+        kept.extend([1, 2, 3]);
+        kept
+    });
+    assert_eq!(kept, [1, 2, 3]);
+    assert_eq!(
+        stats,
+        HeapStats {
+            peak: 2500,
+            allocated: 2500,
+            allocations: 2,
+        }
+    );
+}